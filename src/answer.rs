@@ -0,0 +1,83 @@
+use std::{convert::Infallible, fmt::Display};
+
+use ndarray::Array2;
+
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Int(i128),
+    Text(String),
+    /// A picture drawn by the puzzle, indexed by (row, column).
+    /// Set pixels are lit.
+    Image(Array2<bool>),
+}
+
+impl Answer {
+    /// Renders an image as rows of `#` and `.`, one line per row.
+    fn render_image(image: &Array2<bool>) -> String {
+        image
+            .rows()
+            .into_iter()
+            .map(|row| {
+                row.iter()
+                    .map(|&lit| if lit { '#' } else { '.' })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Int(value) => write!(f, "{}", value),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Image(image) => write!(f, "{}", Self::render_image(image)),
+        }
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for Answer {
+                fn from(value: $int) -> Self {
+                    Answer::Int(value.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(u8, u16, u32, u64, i8, i16, i32, i64, i128);
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Int(value.try_into().unwrap())
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_owned())
+    }
+}
+
+impl From<Array2<bool>> for Answer {
+    fn from(image: Array2<bool>) -> Self {
+        Answer::Image(image)
+    }
+}
+
+impl From<Infallible> for Answer {
+    fn from(value: Infallible) -> Self {
+        match value {}
+    }
+}
@@ -0,0 +1,272 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use anyhow::{bail, Context, Result};
+use aoc2022::{
+    answer::Answer,
+    days::{self, day16::Day16},
+    solution::{AnySolution, Solution},
+    util::day_input_path,
+};
+use clap::{Parser, Subcommand, ValueEnum};
+use serde_json::{json, Value};
+
+#[derive(Debug, Parser)]
+#[command(about = "Solves Advent of Code 2022 puzzles")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Solves the puzzle of a single day, or of every day
+    Run {
+        /// The day to solve, or "all"
+        day: DaySelection,
+
+        /// Solve only this part of the puzzle
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// The puzzle input. Defaults to inputs/dayN.txt
+        #[arg(long)]
+        input: Option<PathBuf>,
+
+        /// How to print the answers
+        #[arg(long, value_enum, default_value_t = Format::Plain)]
+        format: Format,
+
+        /// Print the graph the puzzle is solved on in Graphviz DOT format,
+        /// instead of the answers. Only some days build a graph.
+        #[arg(long)]
+        dot: bool,
+
+        /// How many agents open valves in day 16 part 2. Defaults to 2
+        #[arg(long)]
+        agents: Option<usize>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// Human-readable lines, with timings
+    Plain,
+    /// One JSON object per answer, per line
+    Json,
+    /// Tab-separated day, part, answer and seconds, with a header line
+    Tsv,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DaySelection {
+    All,
+    Day(u8),
+}
+
+impl FromStr for DaySelection {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "all" {
+            return Ok(Self::All);
+        }
+
+        let day: u8 = s.parse().context("Expected a day number or \"all\"")?;
+        if !days::DAYS.contains(&day) {
+            bail!(
+                "Day must be between {} and {}",
+                days::DAYS.start(),
+                days::DAYS.end()
+            );
+        }
+
+        Ok(Self::Day(day))
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            format,
+            dot,
+            agents,
+        } => {
+            if agents.is_some() && day != DaySelection::Day(16) {
+                eprintln!("--agents can only be used when running day 16");
+                return ExitCode::FAILURE;
+            }
+
+            if dot {
+                let DaySelection::Day(day) = day else {
+                    eprintln!("--dot can't be used when running all days");
+                    return ExitCode::FAILURE;
+                };
+
+                let input = input.unwrap_or_else(|| day_input_path(day));
+                return match print_dot(day, &input) {
+                    Ok(_) => ExitCode::SUCCESS,
+                    Err(error) => {
+                        eprintln!("Day {}: {:#}", day, error);
+                        ExitCode::FAILURE
+                    }
+                };
+            }
+
+            if format == Format::Tsv {
+                println!("day\tpart\tanswer\tseconds");
+            }
+
+            match day {
+                DaySelection::Day(day) => {
+                    let input = input.unwrap_or_else(|| day_input_path(day));
+                    match run_day(day, part, &input, format, agents) {
+                        Ok(_) => ExitCode::SUCCESS,
+                        Err(error) => {
+                            eprintln!("Day {}: {:#}", day, error);
+                            ExitCode::FAILURE
+                        }
+                    }
+                }
+                DaySelection::All => {
+                    if input.is_some() {
+                        eprintln!("--input can't be used when running all days");
+                        return ExitCode::FAILURE;
+                    }
+
+                    let mut total = Duration::ZERO;
+                    let mut failed = false;
+                    for day in days::DAYS {
+                        match run_day(day, part, &day_input_path(day), format, None) {
+                            Ok(elapsed) => total += elapsed,
+                            Err(error) => {
+                                eprintln!("Day {}: {:#}", day, error);
+                                failed = true;
+                            }
+                        }
+                    }
+                    if format == Format::Plain {
+                        println!("Total: {:.1?}", total);
+                    }
+
+                    if failed {
+                        ExitCode::FAILURE
+                    } else {
+                        ExitCode::SUCCESS
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Solves the requested parts of a day's puzzle, printing each answer
+/// along with the time it took. Returns the total time spent.
+///
+/// `agents` replaces the number of agents in day 16 part 2.
+fn run_day(
+    day: u8,
+    part: Option<u8>,
+    input: &Path,
+    format: Format,
+    agents: Option<usize>,
+) -> Result<Duration> {
+    let input = fs::read_to_string(input)
+        .with_context(|| format!("Couldn't read input from {}", input.display()))?;
+
+    let start = Instant::now();
+    let solution: Box<dyn AnySolution> = match agents {
+        Some(agents) => Box::new(Day16::parse(&input)?.with_agents(agents)),
+        None => days::parse(day, &input)?,
+    };
+    let mut total = start.elapsed();
+    if format == Format::Plain {
+        println!("Day {} parse ({:.1?})", day, total);
+    }
+
+    let parts = match part {
+        Some(part) => {
+            if part > solution.parts() {
+                bail!("There is no part {}", part);
+            }
+            part..=part
+        }
+        None => 1..=solution.parts(),
+    };
+
+    for part in parts {
+        let start = Instant::now();
+        let answer = solution.solve(part)?;
+        let elapsed = start.elapsed();
+        total += elapsed;
+
+        print_answer(format, day, part, &answer, elapsed);
+    }
+
+    Ok(total)
+}
+
+/// Prints the graph built from a day's input in Graphviz DOT format.
+fn print_dot(day: u8, input: &Path) -> Result<()> {
+    let input = fs::read_to_string(input)
+        .with_context(|| format!("Couldn't read input from {}", input.display()))?;
+
+    let solution = days::parse(day, &input)?;
+    let dot = solution
+        .to_dot()
+        .context("There is no graph to export for this day")??;
+    println!("{}", dot);
+
+    Ok(())
+}
+
+fn print_answer(format: Format, day: u8, part: u8, answer: &Answer, elapsed: Duration) {
+    match format {
+        Format::Plain => {
+            // Multi-line answers, such as pictures, go below the heading
+            let answer = answer.to_string();
+            if answer.contains('\n') {
+                println!("Day {} part {} ({:.1?}):", day, part, elapsed);
+                println!("{}", answer);
+            } else {
+                println!("Day {} part {}: {} ({:.1?})", day, part, answer, elapsed);
+            }
+        }
+        Format::Json => {
+            let (kind, value) = match answer {
+                Answer::Int(value) => (
+                    "int",
+                    // Numbers that don't fit in 64 bits are kept exact as strings
+                    i64::try_from(*value)
+                        .map(Value::from)
+                        .or_else(|_| u64::try_from(*value).map(Value::from))
+                        .unwrap_or_else(|_| Value::from(value.to_string())),
+                ),
+                Answer::Text(text) => ("text", Value::from(text.as_str())),
+                Answer::Image(_) => ("image", Value::from_iter(answer.to_string().lines())),
+            };
+            let object = json!({
+                "day": day,
+                "part": part,
+                "kind": kind,
+                "answer": value,
+                "seconds": elapsed.as_secs_f64(),
+            });
+            println!("{}", object);
+        }
+        Format::Tsv => {
+            let answer = answer.to_string().replace('\n', "\\n");
+            println!("{}\t{}\t{}\t{}", day, part, answer, elapsed.as_secs_f64());
+        }
+    }
+}
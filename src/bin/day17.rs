@@ -0,0 +1,6 @@
+use anyhow::Result;
+use aoc2022::{days::day17::Day17, solution::run};
+
+fn main() -> Result<()> {
+    run::<Day17>()
+}
@@ -0,0 +1,54 @@
+//! Day 1: Calorie Counting.
+
+use anyhow::{bail, Context, Result};
+use itertools::Itertools;
+use nom::{character::complete::line_ending, multi::separated_list1};
+
+use crate::{
+    parse::{blocks, parse_all, unsigned},
+    solution::Solution,
+};
+
+pub struct Day01 {
+    inventories: Vec<Vec<u32>>,
+}
+
+impl Day01 {
+    fn sorted_total_calories(&self) -> Vec<u32> {
+        self.inventories
+            .iter()
+            .map(|inventory| inventory.iter().sum::<u32>())
+            .sorted()
+            .collect_vec()
+    }
+}
+
+impl Solution for Day01 {
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self> {
+        let inventories = parse_all(input, blocks(separated_list1(line_ending, unsigned)))?;
+        Ok(Self { inventories })
+    }
+
+    fn part1(&self) -> Result<u32> {
+        let max = self
+            .sorted_total_calories()
+            .last()
+            .copied()
+            .context("Expected at least one inventory")?;
+        Ok(max)
+    }
+
+    fn part2(&self) -> Result<u32> {
+        let sorted_total_calories = self.sorted_total_calories();
+        if sorted_total_calories.len() < 3 {
+            bail!("Expected at least three inventories");
+        }
+        let top_three = sorted_total_calories[sorted_total_calories.len() - 3..]
+            .iter()
+            .sum::<u32>();
+        Ok(top_three)
+    }
+}
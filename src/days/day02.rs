@@ -0,0 +1,155 @@
+//! Day 2: Rock Paper Scissors.
+
+use anyhow::{bail, Context, Result};
+use itertools::Itertools;
+
+use crate::{parse::map_lines, solution::Solution};
+
+pub struct Day02 {
+    rounds: Vec<Round>,
+    plies_and_outcomes: Vec<(Ply, Outcome)>,
+}
+
+impl Solution for Day02 {
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Self {
+            rounds: parse_as_plys(input)?,
+            plies_and_outcomes: parse_as_ply_and_outcome(input)?,
+        })
+    }
+
+    fn part1(&self) -> Result<u32> {
+        let total_score = self.rounds.iter().map(Round::score).sum();
+        Ok(total_score)
+    }
+
+    fn part2(&self) -> Result<u32> {
+        let total_score = self
+            .plies_and_outcomes
+            .iter()
+            .map(|&(theirs, outcome)| Round::from_ply_and_outcome(theirs, outcome).score())
+            .sum();
+        Ok(total_score)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ply {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Win,
+    Lose,
+    Draw,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Round {
+    theirs: Ply,
+    ours: Ply,
+}
+
+impl Round {
+    pub fn from_ply_and_outcome(theirs: Ply, outcome: Outcome) -> Round {
+        let ours = match (theirs, outcome) {
+            (Ply::Rock, Outcome::Win) => Ply::Paper,
+            (Ply::Rock, Outcome::Lose) => Ply::Scissors,
+            (Ply::Rock, Outcome::Draw) => Ply::Rock,
+            (Ply::Paper, Outcome::Win) => Ply::Scissors,
+            (Ply::Paper, Outcome::Lose) => Ply::Rock,
+            (Ply::Paper, Outcome::Draw) => Ply::Paper,
+            (Ply::Scissors, Outcome::Win) => Ply::Rock,
+            (Ply::Scissors, Outcome::Lose) => Ply::Paper,
+            (Ply::Scissors, Outcome::Draw) => Ply::Scissors,
+        };
+
+        Round { theirs, ours }
+    }
+
+    pub fn outcome(&self) -> Outcome {
+        match (self.ours, self.theirs) {
+            (Ply::Rock, Ply::Rock) => Outcome::Draw,
+            (Ply::Rock, Ply::Paper) => Outcome::Lose,
+            (Ply::Rock, Ply::Scissors) => Outcome::Win,
+            (Ply::Paper, Ply::Rock) => Outcome::Win,
+            (Ply::Paper, Ply::Paper) => Outcome::Draw,
+            (Ply::Paper, Ply::Scissors) => Outcome::Lose,
+            (Ply::Scissors, Ply::Rock) => Outcome::Lose,
+            (Ply::Scissors, Ply::Paper) => Outcome::Win,
+            (Ply::Scissors, Ply::Scissors) => Outcome::Draw,
+        }
+    }
+
+    pub fn score(&self) -> u32 {
+        let ours = match self.ours {
+            Ply::Rock => 1,
+            Ply::Paper => 2,
+            Ply::Scissors => 3,
+        };
+
+        let outcome = match self.outcome() {
+            Outcome::Win => 6,
+            Outcome::Lose => 0,
+            Outcome::Draw => 3,
+        };
+
+        ours + outcome
+    }
+}
+
+fn parse_as_plys(input: &str) -> Result<Vec<Round>> {
+    Ok(map_lines(input, |line| {
+        let (theirs, ours) = line
+            .split_whitespace()
+            .collect_tuple()
+            .context("Too many elements on line")?;
+
+        let theirs = match theirs {
+            "A" => Ply::Rock,
+            "B" => Ply::Paper,
+            "C" => Ply::Scissors,
+            _ => bail!("Unknown ply {}", theirs),
+        };
+
+        let ours = match ours {
+            "X" => Ply::Rock,
+            "Y" => Ply::Paper,
+            "Z" => Ply::Scissors,
+            _ => bail!("Unknown ply {}", ours),
+        };
+
+        Ok(Round { theirs, ours })
+    })?)
+}
+
+fn parse_as_ply_and_outcome(input: &str) -> Result<Vec<(Ply, Outcome)>> {
+    Ok(map_lines(input, |line| {
+        let (theirs, outcome) = line
+            .split_whitespace()
+            .collect_tuple()
+            .context("Too many elements on line")?;
+
+        let theirs = match theirs {
+            "A" => Ply::Rock,
+            "B" => Ply::Paper,
+            "C" => Ply::Scissors,
+            _ => bail!("Unknown ply {}", theirs),
+        };
+
+        let outcome = match outcome {
+            "X" => Outcome::Lose,
+            "Y" => Outcome::Draw,
+            "Z" => Outcome::Win,
+            _ => bail!("Unknown outcome {}", outcome),
+        };
+
+        Ok((theirs, outcome))
+    })?)
+}
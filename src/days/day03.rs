@@ -0,0 +1,142 @@
+//! Day 3: Rucksack Reorganization.
+
+use std::{borrow::Borrow, collections::HashSet};
+
+use anyhow::{bail, Context, Result};
+use itertools::Itertools;
+
+use crate::{parse::map_lines, solution::Solution};
+
+pub struct Day03 {
+    rucksacks: Vec<Rucksack>,
+}
+
+impl Solution for Day03 {
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Self {
+            rucksacks: parse_input(input)?,
+        })
+    }
+
+    fn part1(&self) -> Result<u32> {
+        sum_common_item_priorities(&self.rucksacks)
+    }
+
+    fn part2(&self) -> Result<u32> {
+        sum_group_badge_priorities(&self.rucksacks)
+    }
+}
+
+pub fn sum_common_item_priorities(rucksacks: &[Rucksack]) -> Result<u32> {
+    let common_items: Result<Vec<char>> = rucksacks.iter().map(Rucksack::common_item).collect();
+    let common_items = common_items?;
+
+    let priorities: Result<Vec<u8>> = common_items.iter().copied().map(priority).collect();
+    let priorities = priorities?;
+
+    let priority_sum: u32 = priorities.iter().copied().map(u32::from).sum();
+
+    Ok(priority_sum)
+}
+
+pub fn sum_group_badge_priorities(rucksacks: &[Rucksack]) -> Result<u32> {
+    let priorities = find_group_badges(rucksacks, 3)?
+        .map(priority)
+        .collect::<Result<Vec<u8>>>()?;
+    Ok(priorities.into_iter().map(u32::from).sum())
+}
+
+fn find_group_badges(
+    rucksacks: &[Rucksack],
+    group_size: usize,
+) -> Result<impl Iterator<Item = char>> {
+    if !rucksacks.len().is_multiple_of(group_size) {
+        bail!(
+            "Number of rucksacks ({}) not divisible by group size ({})",
+            rucksacks.len(),
+            group_size
+        );
+    }
+
+    let badges: Result<Vec<char>> = rucksacks
+        .iter()
+        .chunks(group_size)
+        .into_iter()
+        .map(|group| group.collect_tuple().unwrap())
+        .map(|(a, b, c)| badge([a, b, c]))
+        .collect();
+    let badges = badges?;
+
+    Ok(badges.into_iter())
+}
+
+fn badge<I, R>(rucksacks: I) -> Result<char>
+where
+    I: IntoIterator<Item = R>,
+    R: Borrow<Rucksack>,
+{
+    Ok(rucksacks
+        .into_iter()
+        .map(|rucksack| {
+            rucksack
+                .borrow()
+                .first_compartment
+                .union(&rucksack.borrow().second_compartment)
+                .copied()
+                .collect()
+        })
+        .reduce(|acc: HashSet<char>, elem| acc.intersection(&elem).copied().collect())
+        .context("Expected at least one rucksack")?
+        .into_iter()
+        .exactly_one()?)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rucksack {
+    first_compartment: HashSet<char>,
+    second_compartment: HashSet<char>,
+}
+
+impl Rucksack {
+    pub fn common_item(&self) -> Result<char> {
+        let item = self
+            .first_compartment
+            .intersection(&self.second_compartment)
+            .copied()
+            .exactly_one();
+        if item.is_err() {
+            bail!("More than one common item");
+        }
+        Ok(item.unwrap())
+    }
+}
+
+pub fn priority(item: char) -> Result<u8> {
+    match item {
+        'a'..='z' => Ok(1 + item as u8 - b'a'),
+        'A'..='Z' => Ok(27 + item as u8 - b'A'),
+        _ => bail!("Invalid item {}", item),
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Rucksack>> {
+    Ok(map_lines(input, |line| {
+        let items = line.chars().collect_vec();
+
+        if items.len() % 2 != 0 {
+            bail!("Amount of items in rucksack is not even: {}", line);
+        }
+
+        let (first_compartment, second_compartment) = items.split_at(items.len() / 2);
+        let first_compartment = first_compartment.iter().copied().collect();
+        let second_compartment = second_compartment.iter().copied().collect();
+
+        Ok(Rucksack {
+            first_compartment,
+            second_compartment,
+        })
+    })?)
+}
@@ -0,0 +1,85 @@
+//! Day 4: Camp Cleanup.
+
+use std::str::FromStr;
+
+use anyhow::{Context, Result};
+use itertools::Itertools;
+
+use crate::{parse::map_lines, solution::Solution};
+
+pub struct Day04 {
+    assignment_pairs: Vec<(Assignment, Assignment)>,
+}
+
+impl Solution for Day04 {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Self {
+            assignment_pairs: parse_input(input)?,
+        })
+    }
+
+    fn part1(&self) -> Result<usize> {
+        let fully_containing_pairs = self
+            .assignment_pairs
+            .iter()
+            .filter(|(first, second)| first.fully_contains(second) || second.fully_contains(first))
+            .count();
+        Ok(fully_containing_pairs)
+    }
+
+    fn part2(&self) -> Result<usize> {
+        let overlapping_pairs = self
+            .assignment_pairs
+            .iter()
+            .filter(|(first, second)| first.overlaps(second))
+            .count();
+        Ok(overlapping_pairs)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Assignment {
+    start: u32,
+    end: u32,
+}
+
+impl Assignment {
+    pub fn new(start: u32, end: u32) -> Self {
+        assert!(start <= end);
+        Self { start, end }
+    }
+
+    pub fn fully_contains(&self, other: &Self) -> bool {
+        self.start <= other.start && self.end >= other.end
+    }
+
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.start <= other.end && self.end >= other.start
+    }
+}
+
+impl FromStr for Assignment {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (start, end) = s.split('-').collect_tuple().context("Invalid assignment")?;
+        Ok(Self::new(start.parse()?, end.parse()?))
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<(Assignment, Assignment)>> {
+    Ok(map_lines(input, |line| {
+        let (first, second) = line
+            .split(',')
+            .collect_tuple()
+            .context("More than two assignments on line")?;
+
+        let first = first.parse()?;
+        let second = second.parse()?;
+
+        Ok((first, second))
+    })?)
+}
@@ -0,0 +1,279 @@
+//! Day 5: Supply Stacks.
+
+use std::collections::VecDeque;
+
+use anyhow::{bail, Result};
+use itertools::Itertools;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{char, line_ending, satisfy},
+    combinator::{consumed, cut, map, peek, value},
+    multi::{many0, separated_list1},
+    sequence::{delimited, pair, preceded, terminated, tuple},
+    IResult,
+};
+
+use crate::{
+    parse::{parse_all, unsigned, ParseError},
+    solution::Solution,
+};
+
+pub struct Day05 {
+    stacks: Stacks,
+    instructions: Vec<Instruction>,
+}
+
+impl Day05 {
+    fn top_crates(stacks: &Stacks) -> String {
+        stacks.top_crates().map(|(_, crat)| crat as char).collect()
+    }
+}
+
+impl Solution for Day05 {
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self> {
+        let (stacks, instructions) = parse_input(input)?;
+        Ok(Self {
+            stacks,
+            instructions,
+        })
+    }
+
+    fn part1(&self) -> Result<String> {
+        let mut stacks = self.stacks.clone();
+        for instruction in &self.instructions {
+            stacks.move_crates_one_by_one(
+                instruction.count.into(),
+                (instruction.from - 1).into(),
+                (instruction.to - 1).into(),
+            )?;
+        }
+        Ok(Self::top_crates(&stacks))
+    }
+
+    fn part2(&self) -> Result<String> {
+        let mut stacks = self.stacks.clone();
+        for instruction in &self.instructions {
+            stacks.move_crates_in_bulk(
+                instruction.count.into(),
+                (instruction.from - 1).into(),
+                (instruction.to - 1).into(),
+            )?;
+        }
+        Ok(Self::top_crates(&stacks))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stacks {
+    /// The top of each stack is at the front.
+    stacks: Vec<VecDeque<u8>>,
+}
+
+impl Stacks {
+    /// Moves the crates one at a time, which reverses their order.
+    /// Stacks are indexed from zero.
+    pub fn move_crates_one_by_one(&mut self, count: usize, from: usize, to: usize) -> Result<()> {
+        self.check_move(count, from, to)?;
+
+        for _ in 0..count {
+            let element = self.stacks[from].pop_front().unwrap();
+            self.stacks[to].push_front(element);
+        }
+        Ok(())
+    }
+
+    /// Moves the crates all at once, which keeps their order.
+    /// Stacks are indexed from zero.
+    pub fn move_crates_in_bulk(&mut self, count: usize, from: usize, to: usize) -> Result<()> {
+        self.check_move(count, from, to)?;
+
+        let to_move = self.stacks[from].drain(..count).rev().collect_vec();
+        for element in to_move {
+            self.stacks[to].push_front(element);
+        }
+        Ok(())
+    }
+
+    pub fn top_crates(&self) -> impl Iterator<Item = (usize, u8)> + '_ {
+        self.stacks.iter().enumerate().filter_map(|(index, stack)| {
+            if stack.is_empty() {
+                None
+            } else {
+                Some((index, stack[0]))
+            }
+        })
+    }
+
+    fn check_move(&self, count: usize, from: usize, to: usize) -> Result<()> {
+        for stack in [from, to] {
+            if stack >= self.stacks.len() {
+                bail!("There is no stack {}", stack + 1);
+            }
+        }
+
+        let height = self.stacks[from].len();
+        if count > height {
+            bail!(
+                "Can't move {} crates from stack {}, which only has {}",
+                count,
+                from + 1,
+                height
+            );
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Instruction {
+    count: u8,
+    from: u8,
+    to: u8,
+}
+
+fn parse_input(input: &str) -> Result<(Stacks, Vec<Instruction>)> {
+    // Each crate is drawn as `[X]`, and a missing one as blanks
+    let cell = |input| {
+        alt((
+            map(
+                delimited(char('['), satisfy(|c| c.is_ascii_uppercase()), char(']')),
+                |c| Some(c as u8),
+            ),
+            value(None, tag("   ")),
+        ))(input)
+    };
+
+    // Once a row of crates has started, it must be made of whole cells
+    let crate_row = |input| {
+        consumed(terminated(
+            pair(cell, cut(many0(preceded(char(' '), cell)))),
+            cut(peek(line_ending)),
+        ))(input)
+    };
+
+    let numbering = consumed(separated_list1(
+        char(' '),
+        delimited(char(' '), unsigned::<usize>, char(' ')),
+    ));
+
+    let instruction = |input| -> IResult<&str, (&str, Instruction)> {
+        consumed(map(
+            tuple((
+                tag("move "),
+                unsigned,
+                tag(" from "),
+                unsigned,
+                tag(" to "),
+                unsigned,
+            )),
+            |(_, count, _, from, _, to)| Instruction { count, from, to },
+        ))(input)
+    };
+
+    let (rows, (numbering_line, numbers), instructions) = parse_all(
+        input,
+        tuple((
+            many0(terminated(crate_row, line_ending)),
+            terminated(numbering, pair(line_ending, line_ending)),
+            separated_list1(line_ending, instruction),
+        )),
+    )?;
+
+    if numbers.iter().copied().ne(1..=numbers.len()) {
+        bail!(ParseError::at(
+            input,
+            numbering_line,
+            format!("Expected the stacks to be numbered 1 to {}", numbers.len())
+        ));
+    }
+
+    let mut stacks = vec![VecDeque::new(); numbers.len()];
+    for (line, (first, rest)) in rows {
+        if rest.len() >= stacks.len() {
+            bail!(ParseError::at(
+                input,
+                line,
+                format!("Expected at most {} stacks", stacks.len())
+            ));
+        }
+
+        for (stack, cell) in stacks.iter_mut().zip(std::iter::once(first).chain(rest)) {
+            if let Some(crat) = cell {
+                stack.push_back(crat);
+            }
+        }
+    }
+
+    let instructions = instructions
+        .into_iter()
+        .map(|(line, instruction)| {
+            for stack in [instruction.from, instruction.to] {
+                if stack == 0 || usize::from(stack) > stacks.len() {
+                    bail!(ParseError::at(
+                        input,
+                        line,
+                        format!("There is no stack {}", stack)
+                    ));
+                }
+            }
+            Ok(instruction)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok((Stacks { stacks }, instructions))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day05;
+    use crate::{parse::ParseError, solution::Solution};
+
+    const STACKS: &str = "[A]    \n[B] [C]\n 1   2 \n\n";
+
+    /// Where parsing the stacks followed by the instructions fails.
+    fn error_location(input: &str) -> (usize, usize) {
+        let error = Day05::parse(input).err().unwrap();
+        let error = error.downcast_ref::<ParseError>().unwrap();
+        (error.line(), error.column())
+    }
+
+    #[test]
+    fn malformed_stacks() {
+        assert_eq!(
+            error_location("[A] [B\n 1   2 \n\nmove 1 from 1 to 2"),
+            (1, 4)
+        );
+        assert_eq!(error_location("[A] [B]\n 1 \n\nmove 1 from 1 to 1"), (1, 1));
+        assert_eq!(
+            error_location("[A] [B]\n 1   3 \n\nmove 1 from 1 to 1"),
+            (2, 1)
+        );
+    }
+
+    #[test]
+    fn missing_stacks() {
+        let input = format!("{}move 1 from 1 to 2\nmove 1 from 0 to 1", STACKS);
+        assert_eq!(error_location(&input), (6, 1));
+
+        let input = format!("{}move 1 from 2 to 3", STACKS);
+        assert_eq!(error_location(&input), (5, 1));
+    }
+
+    #[test]
+    fn too_many_crates() {
+        let day =
+            Day05::parse(&format!("{}move 2 from 2 to 1\nmove 2 from 2 to 1", STACKS)).unwrap();
+        assert!(day.part1().is_err());
+        assert!(day.part2().is_err());
+
+        let day = Day05::parse(&format!("{}move 2 from 1 to 2", STACKS)).unwrap();
+        // Moving both crates empties the first stack
+        assert_eq!(day.part1().unwrap(), "B");
+        assert_eq!(day.part2().unwrap(), "A");
+    }
+}
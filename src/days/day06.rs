@@ -0,0 +1,56 @@
+//! Day 6: Tuning Trouble.
+
+use anyhow::{bail, Context, Result};
+use itertools::Itertools;
+
+use crate::solution::Solution;
+
+pub struct Day06 {
+    datastream: Vec<u8>,
+}
+
+impl Solution for Day06 {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Self {
+            datastream: parse_input(input)?,
+        })
+    }
+
+    fn part1(&self) -> Result<usize> {
+        find_start_of_packet(&self.datastream).context("SOP not found")
+    }
+
+    fn part2(&self) -> Result<usize> {
+        find_start_of_message(&self.datastream).context("SOM not found")
+    }
+}
+
+/// Returns the index of the start-of-packet marker
+pub fn find_start_of_packet(datastream: &[u8]) -> Option<usize> {
+    datastream
+        .windows(4)
+        .find_position(|window| window.iter().all_unique())
+        .map(|(index, _window)| index + 4)
+}
+
+pub fn find_start_of_message(datastream: &[u8]) -> Option<usize> {
+    datastream
+        .windows(14)
+        .find_position(|window| window.iter().all_unique())
+        .map(|(index, _window)| index + 14)
+}
+
+fn parse_input(input: &str) -> Result<Vec<u8>> {
+    let datastream = input
+        .lines()
+        .exactly_one()
+        .ok()
+        .context("Expected only one input line")?;
+    if !datastream.is_ascii() {
+        bail!("Expected ASCII string");
+    }
+    Ok(datastream.as_bytes().to_vec())
+}
@@ -0,0 +1,247 @@
+//! Day 7: No Space Left On Device.
+
+use std::{borrow::Borrow, collections::HashMap, iter, str::FromStr};
+
+use anyhow::{bail, Context, Result};
+use itertools::Itertools;
+use lazy_static::lazy_static;
+use petgraph::{
+    dot::{Config, Dot},
+    matrix_graph::{DiMatrix, NodeIndex},
+    visit::DfsPostOrder,
+};
+use regex::Regex;
+
+use crate::{parse::map_lines, solution::Solution};
+
+const DISK_SIZE: u32 = 70_000_000;
+const REQUIRED_FREE_SPACE: u32 = 30_000_000;
+
+pub struct Day07 {
+    tree: Fs,
+}
+
+impl Day07 {
+    pub fn tree(&self) -> &Fs {
+        &self.tree
+    }
+}
+
+impl Solution for Day07 {
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self> {
+        let lines: Vec<Line> = map_lines(input, str::parse)?;
+        Ok(Self {
+            tree: Fs::parse(lines)?,
+        })
+    }
+
+    fn part1(&self) -> Result<u32> {
+        let sum_of_sizes = self
+            .tree
+            .directory_sizes()
+            .filter(|&size| size <= 100_000)
+            .sum();
+        Ok(sum_of_sizes)
+    }
+
+    fn part2(&self) -> Result<u32> {
+        let directory_sizes = self.tree.directory_sizes().sorted().collect_vec();
+
+        // The largest directory is the root, since it contains everything else
+        let used_space = *directory_sizes.last().unwrap();
+
+        let free_space = DISK_SIZE - used_space;
+
+        let size_to_free = directory_sizes
+            .iter()
+            .copied()
+            .find(|&size| free_space + size >= REQUIRED_FREE_SPACE)
+            .context("Didn't find a directory to free")?;
+        Ok(size_to_free)
+    }
+
+    fn to_dot(&self) -> Option<Result<String>> {
+        Some(Ok(self.tree.to_dot()))
+    }
+}
+
+#[derive(Clone)]
+pub struct Fs {
+    root: NodeIndex<u16>,
+    graph: DiMatrix<FsObject, ()>,
+}
+
+#[derive(Debug, Clone)]
+pub enum FsObject {
+    Directory { name: String },
+    File { name: String, size: u32 },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Line {
+    Instruction(Instruction),
+    Directory { name: String },
+    File { name: String, size: u32 },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Instruction {
+    ChangeDirectory(String),
+    ListDirectory,
+}
+
+impl Fs {
+    pub fn directory_sizes(&self) -> impl Iterator<Item = u32> + '_ {
+        self.directory_sizes_by_node()
+            .into_iter()
+            .map(|(_, size)| size)
+    }
+
+    /// The total size of every directory, along with its node.
+    /// Subdirectories come before the directories containing them.
+    fn directory_sizes_by_node(&self) -> Vec<(NodeIndex<u16>, u32)> {
+        let mut post_order = DfsPostOrder::new(&self.graph, self.root);
+        let mut sizes = HashMap::<NodeIndex<u16>, u32>::new();
+        iter::from_fn(move || post_order.next(&self.graph))
+            .filter_map(move |node| match self.graph.node_weight(node) {
+                FsObject::Directory { .. } => {
+                    let size = self
+                        .graph
+                        .neighbors(node)
+                        .map(|neighbour| match self.graph.node_weight(neighbour) {
+                            FsObject::Directory { .. } => sizes[&neighbour],
+                            FsObject::File { size, .. } => *size,
+                        })
+                        .sum();
+                    sizes.insert(node, size);
+                    Some((node, size))
+                }
+                FsObject::File { .. } => None,
+            })
+            .collect()
+    }
+
+    /// Renders the tree in Graphviz DOT format, labeling everything
+    /// the way the puzzle text does, with the total size of every directory.
+    pub fn to_dot(&self) -> String {
+        let sizes: HashMap<_, _> = self.directory_sizes_by_node().into_iter().collect();
+
+        let node_attributes = |_, (node, object): (NodeIndex<u16>, &FsObject)| {
+            let (label, shape) = match object {
+                FsObject::Directory { name } => {
+                    (format!("{} (dir, size={})", name, sizes[&node]), "folder")
+                }
+                FsObject::File { name, size } => {
+                    (format!("{} (file, size={})", name, size), "note")
+                }
+            };
+            format!("label = {:?}, shape = {}", label, shape)
+        };
+
+        let dot = Dot::with_attr_getters(
+            &self.graph,
+            &[Config::NodeNoLabel, Config::EdgeNoLabel],
+            &|_, _| String::new(),
+            &node_attributes,
+        );
+        format!("{:?}", dot)
+    }
+
+    pub fn parse(lines: impl IntoIterator<Item = impl Borrow<Line>>) -> Result<Self> {
+        let mut graph = DiMatrix::new();
+
+        let root = graph.add_node(FsObject::Directory {
+            name: "/".to_owned(),
+        });
+
+        let mut current_path = vec![root];
+
+        for line in lines {
+            match line.borrow() {
+                Line::Instruction(Instruction::ListDirectory) => {}
+                Line::Instruction(Instruction::ChangeDirectory(new_dir_name)) => {
+                    match new_dir_name.as_str() {
+                        "/" => {
+                            current_path.drain(1..);
+                        }
+                        ".." => {
+                            current_path.pop().context("Attempting to cd past /")?;
+                        }
+                        _ => {
+                            let current_dir = *current_path.last().unwrap();
+
+                            if let Some(new_directory) =
+                                graph.neighbors(current_dir).find(|&neighbour| {
+                                    match graph.node_weight(neighbour) {
+                                        FsObject::Directory { name } => name == new_dir_name,
+                                        _ => false,
+                                    }
+                                })
+                            {
+                                current_path.push(new_directory);
+                            } else {
+                                bail!("Expected current directory to contain {}", new_dir_name);
+                            }
+                        }
+                    }
+                }
+                Line::Directory { name } => {
+                    let node = graph.add_node(FsObject::Directory { name: name.clone() });
+                    graph.add_edge(*current_path.last().unwrap(), node, ());
+                }
+                Line::File { name, size } => {
+                    let node = graph.add_node(FsObject::File {
+                        name: name.clone(),
+                        size: *size,
+                    });
+                    graph.add_edge(*current_path.last().unwrap(), node, ());
+                }
+            }
+        }
+
+        Ok(Self { root, graph })
+    }
+}
+
+impl FromStr for Line {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref INSTRUCTION_REGEX: Regex = Regex::new(r#"^\$ (cd|ls)(?: (.+)|)$"#).unwrap();
+            static ref DIR_REGEX: Regex = Regex::new(r#"^dir (.+)$"#).unwrap();
+            static ref FILE_REGEX: Regex = Regex::new(r#"^(\d+) (.+)$"#).unwrap();
+        }
+
+        if let Some(captures) = INSTRUCTION_REGEX.captures(s) {
+            let command = captures.get(1).unwrap().as_str();
+            match command {
+                "cd" => {
+                    if let Some(argument) = captures.get(2) {
+                        Ok(Self::Instruction(Instruction::ChangeDirectory(
+                            argument.as_str().to_owned(),
+                        )))
+                    } else {
+                        bail!("Expected argument to 'cd' command");
+                    }
+                }
+                "ls" => Ok(Self::Instruction(Instruction::ListDirectory)),
+                _ => bail!("Unknown command '{}'", command),
+            }
+        } else if let Some(captures) = DIR_REGEX.captures(s) {
+            Ok(Self::Directory {
+                name: captures.get(1).unwrap().as_str().to_owned(),
+            })
+        } else if let Some(captures) = FILE_REGEX.captures(s) {
+            Ok(Self::File {
+                name: captures.get(2).unwrap().as_str().to_owned(),
+                size: captures.get(1).unwrap().as_str().parse()?,
+            })
+        } else {
+            bail!("Invalid line {}", s);
+        }
+    }
+}
@@ -0,0 +1,72 @@
+//! Day 8: Treetop Tree House.
+
+use anyhow::Result;
+use nalgebra::Point2;
+
+use crate::{
+    grid::{Grid, ORTHOGONAL},
+    solution::Solution,
+};
+
+pub struct Day08 {
+    forest: Grid<u8>,
+}
+
+impl Solution for Day08 {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self> {
+        let forest = Grid::parse(input, |c| c.to_digit(10).map(|digit| digit as u8))?;
+        Ok(Self { forest })
+    }
+
+    fn part1(&self) -> Result<usize> {
+        Ok(count_visible(&self.forest))
+    }
+
+    fn part2(&self) -> Result<usize> {
+        Ok(highest_scenic_score(&self.forest))
+    }
+}
+
+/// Counts the trees that can be seen from outside the forest,
+/// looking along a row or a column.
+pub fn count_visible(forest: &Grid<u8>) -> usize {
+    forest
+        .points()
+        .filter(|&tree| {
+            ORTHOGONAL.into_iter().any(|direction| {
+                forest
+                    .ray(tree, direction)
+                    .all(|other| forest[other] < forest[tree])
+            })
+        })
+        .count()
+}
+
+pub fn highest_scenic_score(forest: &Grid<u8>) -> usize {
+    forest
+        .points()
+        .map(|tree| scenic_score(forest, tree))
+        .max()
+        .unwrap()
+}
+
+pub fn scenic_score(forest: &Grid<u8>, tree: Point2<usize>) -> usize {
+    ORTHOGONAL
+        .into_iter()
+        .map(|direction| {
+            // Count trees up to and including the first one that blocks the view
+            let mut blocked = false;
+            forest
+                .ray(tree, direction)
+                .take_while(|&other| {
+                    let visible = !blocked;
+                    blocked = forest[other] >= forest[tree];
+                    visible
+                })
+                .count()
+        })
+        .product()
+}
@@ -0,0 +1,142 @@
+//! Day 9: Rope Bridge.
+
+use std::{num::NonZeroU32, str::FromStr};
+
+use anyhow::{bail, Context, Result};
+use itertools::Itertools;
+use nalgebra::{Point2, Vector2};
+
+use crate::{parse::map_lines, solution::Solution};
+
+pub struct Day09 {
+    movements: Vec<Vector2<i32>>,
+}
+
+impl Solution for Day09 {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Self {
+            movements: parse_input(input)?,
+        })
+    }
+
+    fn part1(&self) -> Result<usize> {
+        let unique_locations = simulate1(self.movements.iter().copied())
+            .into_iter()
+            .unique()
+            .count();
+        Ok(unique_locations)
+    }
+
+    fn part2(&self) -> Result<usize> {
+        let unique_locations =
+            simulate_many(self.movements.iter().copied(), NonZeroU32::new(9).unwrap())
+                .into_iter()
+                .unique()
+                .count();
+        Ok(unique_locations)
+    }
+}
+
+pub fn simulate_many(
+    movements: impl IntoIterator<Item = Vector2<i32>>,
+    knots: NonZeroU32,
+) -> Vec<Point2<i32>> {
+    let mut knot_positions = simulate1(movements);
+
+    for _ in 0..knots.get() - 1 {
+        // Convert positions to movements
+
+        let mut movements = vec![];
+
+        for i in 1..knot_positions.len() {
+            let delta = knot_positions[i] - knot_positions[i - 1];
+
+            assert!(delta.x.abs() <= 1);
+            assert!(delta.y.abs() <= 1);
+
+            movements.push(delta);
+        }
+
+        // Move the next knot
+
+        knot_positions = simulate1(movements);
+    }
+
+    knot_positions
+}
+
+fn simulate1(movements: impl IntoIterator<Item = Vector2<i32>>) -> Vec<Point2<i32>> {
+    let mut tail_visited = vec![Point2::new(0, 0)];
+    let mut head_position = Point2::<i32>::new(0, 0);
+
+    for movement in movements {
+        // Move the head
+
+        head_position += movement;
+
+        // Move the tail
+
+        let tail_position = *tail_visited.last().unwrap();
+
+        let delta = head_position - tail_position;
+        assert!(delta.x.abs() <= 2);
+        assert!(delta.y.abs() <= 2);
+
+        if delta.x.abs() == 2 || delta.y.abs() == 2 {
+            tail_visited.push(tail_position + Vector2::new(delta.x.signum(), delta.y.signum()));
+        }
+    }
+
+    tail_visited
+}
+
+fn parse_input(input: &str) -> Result<Vec<Vector2<i32>>> {
+    let motions = map_lines(input, |line| {
+        let (direction, steps) = line
+            .split_whitespace()
+            .collect_tuple()
+            .context("Invalid string format")?;
+
+        let direction: Direction = direction.parse()?;
+        let steps: u8 = steps.parse().context("Invalid number of steps")?;
+
+        Ok((direction, steps))
+    })?;
+
+    Ok(motions
+        .into_iter()
+        .flat_map(|(direction, steps)| {
+            (0..steps).map(move |_| match direction {
+                Direction::Up => Vector2::new(0, 1),
+                Direction::Down => Vector2::new(0, -1),
+                Direction::Left => Vector2::new(-1, 0),
+                Direction::Right => Vector2::new(1, 0),
+            })
+        })
+        .collect())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl FromStr for Direction {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "U" => Ok(Self::Up),
+            "D" => Ok(Self::Down),
+            "L" => Ok(Self::Left),
+            "R" => Ok(Self::Right),
+            _ => bail!("Invalid direction {}", s),
+        }
+    }
+}
@@ -0,0 +1,208 @@
+//! Day 10: Cathode-Ray Tube.
+
+use std::str::FromStr;
+
+use anyhow::{bail, Result};
+use itertools::Itertools;
+use ndarray::Array2;
+
+use crate::{ocr::recognize, parse::map_lines, solution::Solution};
+
+pub struct Day10 {
+    program: Vec<Instruction>,
+}
+
+impl Day10 {
+    pub fn program(&self) -> &[Instruction] {
+        &self.program
+    }
+
+    /// Runs the program and returns the picture left on the screen,
+    /// indexed by (row, column).
+    pub fn screen(&self) -> Array2<bool> {
+        let mut system = System::new(Cpu::new(self.program.iter().copied()));
+        system.run();
+
+        Array2::from_shape_fn(
+            (System::SCREEN_HEIGHT, System::SCREEN_WIDTH),
+            |(row, column)| system.pixel_at(row, column),
+        )
+    }
+}
+
+impl Solution for Day10 {
+    type Part1 = i64;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Self {
+            program: map_lines(input, str::parse)?,
+        })
+    }
+
+    fn part1(&self) -> Result<i64> {
+        let mut cpu = Cpu::new(self.program.iter().copied());
+        let mut signal_strength_sum = 0;
+        for cycle_count in (19..=219).step_by(40) {
+            while cpu.cycle_count() != cycle_count {
+                if !cpu.execute_cycle() {
+                    bail!("Program ended before cycle {}", cycle_count + 1);
+                }
+            }
+
+            // We're actually interested in the X value *during* the next cycle.
+            // It's the same value, but we need to adjust the computation a little.
+            let signal_strength = (cpu.cycle_count() + 1) as i64 * cpu.x_register() as i64;
+            signal_strength_sum += signal_strength;
+        }
+        Ok(signal_strength_sum)
+    }
+
+    fn part2(&self) -> Result<String> {
+        Ok(recognize(self.screen().view())?)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    AddX(i32),
+    NoOp,
+}
+
+impl FromStr for Instruction {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s == "noop" {
+            Ok(Self::NoOp)
+        } else if let Some(("addx", operand)) = s.split_whitespace().collect_tuple() {
+            let operand: i32 = operand.parse()?;
+            Ok(Self::AddX(operand))
+        } else {
+            bail!("Invalid instruction {}", s)
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Cpu {
+    program: Vec<Instruction>,
+    cycle_count: u32,
+    x_register: i32,
+    program_counter: usize,
+    state: CpuState,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CpuState {
+    Idle,
+    ExecutingAddX,
+}
+
+impl Cpu {
+    pub fn new(program: impl IntoIterator<Item = Instruction>) -> Self {
+        Self {
+            program: program.into_iter().collect(),
+            cycle_count: 0,
+            x_register: 1,
+            program_counter: 0,
+            state: CpuState::Idle,
+        }
+    }
+
+    pub fn cycle_count(&self) -> u32 {
+        self.cycle_count
+    }
+
+    pub fn x_register(&self) -> i32 {
+        self.x_register
+    }
+
+    pub fn execute_cycle(&mut self) -> bool {
+        if self.program_counter >= self.program.len() {
+            return false;
+        }
+
+        match self.state {
+            CpuState::Idle => {
+                // Idle state. Read the next instruction.
+                let instruction = self.program[self.program_counter];
+                match instruction {
+                    Instruction::AddX(_) => self.state = CpuState::ExecutingAddX,
+                    Instruction::NoOp => self.state = CpuState::Idle,
+                }
+            }
+            CpuState::ExecutingAddX => {
+                match self.program[self.program_counter] {
+                    Instruction::AddX(argument) => self.x_register += argument,
+                    _ => unreachable!(),
+                }
+                self.state = CpuState::Idle;
+            }
+        }
+
+        // If after execution we're in the idle state, then the instruction
+        // is done. Increment PC.
+        if let CpuState::Idle = self.state {
+            self.program_counter += 1
+        }
+
+        self.cycle_count += 1;
+
+        true
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct System {
+    cpu: Cpu,
+    screen: [bool; Self::SCREEN_WIDTH * Self::SCREEN_HEIGHT],
+}
+
+impl System {
+    const SCREEN_WIDTH: usize = 40;
+    const SCREEN_HEIGHT: usize = 6;
+
+    pub fn new(cpu: Cpu) -> Self {
+        Self {
+            cpu,
+            screen: [false; Self::SCREEN_WIDTH * Self::SCREEN_HEIGHT],
+        }
+    }
+
+    pub fn pixel_at(&self, row: usize, column: usize) -> bool {
+        assert!(row < Self::SCREEN_HEIGHT);
+        assert!(column < Self::SCREEN_WIDTH);
+        self.screen[row * Self::SCREEN_WIDTH + column]
+    }
+
+    pub fn run(&mut self) {
+        loop {
+            // Once the whole screen has been drawn, the beam would wrap around
+            // and overwrite the first pixels
+            if self.cpu.cycle_count() as usize >= Self::SCREEN_WIDTH * Self::SCREEN_HEIGHT {
+                break;
+            }
+
+            // During the next cycle, the beam will draw the pixel at this position.
+            let horizontal_position: usize = self.cpu.cycle_count() as usize % Self::SCREEN_WIDTH;
+            let vertical_position: usize =
+                (self.cpu.cycle_count() as usize / Self::SCREEN_WIDTH) % Self::SCREEN_HEIGHT;
+
+            let sprite_middle = self.cpu.x_register();
+            let sprite_left = sprite_middle - 1;
+            let sprite_right = sprite_middle + 1;
+
+            let pixel_index: usize = vertical_position * Self::SCREEN_WIDTH + horizontal_position;
+
+            self.screen[pixel_index] = sprite_left == horizontal_position.try_into().unwrap()
+                || sprite_right == horizontal_position.try_into().unwrap()
+                || sprite_middle == horizontal_position.try_into().unwrap();
+
+            if !self.cpu.execute_cycle() {
+                break;
+            }
+        }
+    }
+}
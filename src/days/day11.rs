@@ -0,0 +1,467 @@
+//! Day 11: Monkey in the Middle.
+
+use std::{
+    cmp::Reverse,
+    collections::BTreeMap,
+    fmt::Display,
+    num::NonZeroU32,
+    ops::{AddAssign, DivAssign, MulAssign},
+};
+
+use anyhow::{bail, Context, Result};
+use itertools::Itertools;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::{
+    galois::{GFInt, ResidueInt, ResidueSystem, WrappingInt},
+    parse::map_blocks,
+    solution::Solution,
+};
+
+pub struct Day11 {
+    monkeys: Vec<Monkey>,
+}
+
+impl Day11 {
+    pub fn monkeys(&self) -> &[Monkey] {
+        &self.monkeys
+    }
+}
+
+impl Solution for Day11 {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Self {
+            monkeys: parse_input(input)?,
+        })
+    }
+
+    fn part1(&self) -> Result<usize> {
+        let monkey_business = simulate(&mut self.monkeys.clone(), NonZeroU32::new(3).unwrap(), 20);
+        Ok(monkey_business)
+    }
+
+    fn part2(&self) -> Result<usize> {
+        let mut monkeys = to_residues(&self.monkeys)?;
+        let monkey_business = simulate(&mut monkeys, NonZeroU32::new(1).unwrap(), 10000);
+        Ok(monkey_business)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    id: usize,
+    items: Vec<Item>,
+    operation: Operation,
+    test: NonZeroU32,
+    target_true: usize,
+    target_false: usize,
+    inspections: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    MulConst(u32),
+    AddConst(u32),
+    MulSelf,
+}
+
+impl Monkey {
+    pub fn items(&self) -> &[Item] {
+        &self.items
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Item {
+    /// The worry level itself.
+    Exact(WrappingInt),
+    /// The worry level modulo the divisors of all the monkeys' tests,
+    /// which is all the tests need, as long as nothing is divided.
+    Residues(ResidueInt),
+}
+
+impl Item {
+    /// The worry level, or for residues, the smallest worry level they match.
+    pub fn worry_level(&self) -> Option<u128> {
+        match self {
+            Item::Exact(value) => Some(value.value().into()),
+            Item::Residues(residues) => residues.to_u128().ok(),
+        }
+    }
+
+    fn square_assign(&mut self) {
+        match self {
+            Item::Exact(value) => value.square_assign(),
+            Item::Residues(residues) => residues.square_assign(),
+        }
+    }
+
+    fn is_divisible_by(&self, divisor: NonZeroU32) -> bool {
+        match self {
+            Item::Exact(value) => value.value() % divisor == 0,
+            Item::Residues(residues) => residues
+                .is_divisible_by(divisor)
+                .expect("Residues are kept for all the divisors"),
+        }
+    }
+}
+
+impl AddAssign<u32> for Item {
+    fn add_assign(&mut self, rhs: u32) {
+        match self {
+            Item::Exact(value) => *value += WrappingInt::new(rhs),
+            Item::Residues(residues) => *residues += &residues.system().create_value(rhs.into()),
+        }
+    }
+}
+
+impl MulAssign<u32> for Item {
+    fn mul_assign(&mut self, rhs: u32) {
+        match self {
+            Item::Exact(value) => *value *= WrappingInt::new(rhs),
+            Item::Residues(residues) => *residues *= &residues.system().create_value(rhs.into()),
+        }
+    }
+}
+
+impl DivAssign<u32> for Item {
+    fn div_assign(&mut self, rhs: u32) {
+        match self {
+            Item::Exact(value) => {
+                *value = WrappingInt::new(value.value().checked_div(rhs).unwrap());
+            }
+            Item::Residues(_) => assert_eq!(rhs, 1, "Residues can't be divided"),
+        }
+    }
+}
+
+impl Display for Item {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Item::Exact(value) => write!(f, "{}", value.value()),
+            Item::Residues(residues) => match (residues.to_u128(), residues.system().modulus()) {
+                (Ok(value), Some(modulus)) => write!(f, "{} (mod {})", value, modulus),
+                _ => write!(
+                    f,
+                    "{:?}",
+                    residues.residues().iter().map(GFInt::value).collect_vec()
+                ),
+            },
+        }
+    }
+}
+
+/// Switches all the items to residues modulo the monkeys' divisors,
+/// so that their worry levels can grow without bound.
+fn to_residues(monkeys: &[Monkey]) -> Result<Vec<Monkey>> {
+    let system = ResidueSystem::new(coprime_moduli(monkeys.iter().map(|monkey| monkey.test)))
+        .expect("Powers of distinct primes are pairwise coprime");
+
+    let mut monkeys = monkeys.to_vec();
+    for monkey in monkeys.iter_mut() {
+        for item in monkey.items.iter_mut() {
+            let value = item
+                .worry_level()
+                .context("Expected the items to start out exact")?;
+            *item = Item::Residues(system.create_value(value));
+        }
+    }
+    Ok(monkeys)
+}
+
+/// Splits the divisors into powers of distinct primes, keeping the highest
+/// power of each. Every divisor divides the product of these, even when
+/// the divisors themselves have common factors.
+fn coprime_moduli(divisors: impl IntoIterator<Item = NonZeroU32>) -> Vec<NonZeroU32> {
+    let mut powers: BTreeMap<u32, u32> = BTreeMap::new();
+    for divisor in divisors {
+        let mut remaining = divisor.get();
+        let mut prime = 2;
+        while remaining > 1 {
+            if prime > remaining / prime {
+                // Whatever's left has no smaller factors
+                prime = remaining;
+            }
+
+            let mut power = 1;
+            while remaining % prime == 0 {
+                remaining /= prime;
+                power *= prime;
+            }
+            if power > 1 {
+                let highest = powers.entry(prime).or_insert(1);
+                *highest = (*highest).max(power);
+            }
+
+            prime += 1;
+        }
+    }
+
+    powers
+        .into_values()
+        .map(|power| NonZeroU32::new(power).unwrap())
+        .collect()
+}
+
+pub fn simulate(monkeys: &mut [Monkey], divide_by: NonZeroU32, iterations: u32) -> usize {
+    for _ in 0..iterations {
+        simulate1(monkeys, divide_by);
+    }
+
+    monkeys.sort_by_key(|monkey| Reverse(monkey.inspections));
+
+    let first = {
+        monkeys.select_nth_unstable_by_key(0, |monkey| Reverse(monkey.inspections));
+        monkeys[0].inspections
+    };
+
+    let second = {
+        monkeys.select_nth_unstable_by_key(1, |monkey| Reverse(monkey.inspections));
+        monkeys[1].inspections
+    };
+
+    first * second
+}
+
+fn simulate1(monkeys: &mut [Monkey], divide_by: NonZeroU32) {
+    for index in 0..monkeys.len() {
+        let monkey = &monkeys[index];
+        assert!(monkey.id == index);
+        let replacement = Monkey {
+            id: monkey.id,
+            items: vec![],
+            operation: monkey.operation,
+            test: monkey.test,
+            target_true: monkey.target_true,
+            target_false: monkey.target_false,
+            inspections: monkey.inspections,
+        };
+        let monkey = std::mem::replace(&mut monkeys[index], replacement);
+
+        for mut item in monkey.items {
+            match monkey.operation {
+                Operation::MulConst(val) => item *= val,
+                Operation::AddConst(val) => item += val,
+                Operation::MulSelf => item.square_assign(),
+            };
+            item /= divide_by.get();
+
+            let should_branch = item.is_divisible_by(monkey.test);
+
+            let target = if should_branch {
+                monkey.target_true
+            } else {
+                monkey.target_false
+            };
+
+            monkeys[target].items.push(item);
+
+            monkeys[index].inspections += 1;
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Monkey>> {
+    let monkeys = map_blocks(input, |descriptor| {
+        if descriptor.len() != 6 {
+            bail!(
+                "Invalid number of lines in descriptor: {}",
+                descriptor.len()
+            );
+        }
+
+        lazy_static! {
+            static ref ID_REGEX: Regex = Regex::new(r#"^Monkey (\d+):$"#).unwrap();
+        }
+        let id = ID_REGEX
+            .captures(descriptor[0])
+            .context("Invalid ID line format")?
+            .get(1)
+            .unwrap()
+            .as_str()
+            .parse()?;
+
+        lazy_static! {
+            static ref ITEMS_REGEX: Regex = Regex::new(r#"^  Starting items: (.+)$"#).unwrap();
+        }
+        let items = ITEMS_REGEX
+            .captures(descriptor[1])
+            .context("Invalid starting items format")?
+            .get(1)
+            .unwrap()
+            .as_str()
+            .split(", ")
+            .map(|s| Ok(Item::Exact(WrappingInt::new(s.parse()?))))
+            .collect::<Result<_>>()?;
+
+        lazy_static! {
+            static ref MUL_CONST_REGEX: Regex =
+                Regex::new(r#"^  Operation: new = old \* (\d+)$"#).unwrap();
+            static ref ADD_CONST_REGEX: Regex =
+                Regex::new(r#"^  Operation: new = old \+ (\d+)$"#).unwrap();
+            static ref MUL_SELF_REGEX: Regex =
+                Regex::new(r#"^  Operation: new = old \* old$"#).unwrap();
+        }
+        let operation = if let Some(mul_const) = MUL_CONST_REGEX.captures(descriptor[2]) {
+            Operation::MulConst(mul_const.get(1).unwrap().as_str().parse()?)
+        } else if let Some(add_const) = ADD_CONST_REGEX.captures(descriptor[2]) {
+            Operation::AddConst(add_const.get(1).unwrap().as_str().parse()?)
+        } else if MUL_SELF_REGEX.is_match(descriptor[2]) {
+            Operation::MulSelf
+        } else {
+            bail!("Invalid operation {}", descriptor[2]);
+        };
+
+        lazy_static! {
+            static ref TEST_REGEX: Regex = Regex::new(r#"^  Test: divisible by (\d+)$"#).unwrap();
+        }
+        let test = TEST_REGEX
+            .captures(descriptor[3])
+            .context("Invalid test expression")?
+            .get(1)
+            .unwrap()
+            .as_str()
+            .parse()?;
+
+        lazy_static! {
+            static ref IF_TRUE_REGEX: Regex =
+                Regex::new(r#"^    If true: throw to monkey (\d+)$"#).unwrap();
+            static ref IF_FALSE_REGEX: Regex =
+                Regex::new(r#"^    If false: throw to monkey (\d+)$"#).unwrap();
+        }
+        let target_true = IF_TRUE_REGEX
+            .captures(descriptor[4])
+            .context("Invalid 'true' target")?
+            .get(1)
+            .unwrap()
+            .as_str()
+            .parse()?;
+        let target_false = IF_FALSE_REGEX
+            .captures(descriptor[5])
+            .context("Invalid 'true' target")?
+            .get(1)
+            .unwrap()
+            .as_str()
+            .parse()?;
+
+        Ok(Monkey {
+            id,
+            items,
+            operation,
+            test,
+            target_true,
+            target_false,
+            inspections: 0,
+        })
+    })?;
+
+    for (index, monkey) in monkeys.iter().enumerate() {
+        if index != monkey.id {
+            bail!("Monkeys are not ordered correctly");
+        }
+    }
+
+    Ok(monkeys)
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::{parse_input, simulate1, to_residues, Operation};
+
+    #[test]
+    fn residues_match_exact_worry_levels() {
+        check_residues(
+            include_str!("../../tests/examples/day11.txt"),
+            23 * 19 * 13 * 17,
+        );
+    }
+
+    #[test]
+    fn residues_with_common_factors() {
+        let input = "\
+Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 4
+    If true: throw to monkey 1
+    If false: throw to monkey 2
+
+Monkey 1:
+  Starting items: 54, 65, 75
+  Operation: new = old + 6
+  Test: divisible by 6
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+  Starting items: 79, 60, 97
+  Operation: new = old * old
+  Test: divisible by 18
+    If true: throw to monkey 0
+    If false: throw to monkey 1
+";
+        // The least common multiple of the divisors
+        check_residues(input, 36);
+    }
+
+    /// Runs the monkeys with exact worry levels for as long as they fit,
+    /// and checks that the residues agree with them every round.
+    fn check_residues(input: &str, modulus: u128) {
+        let monkeys = parse_input(input).unwrap();
+
+        let mut exact = monkeys
+            .iter()
+            .map(|monkey| {
+                monkey
+                    .items()
+                    .iter()
+                    .map(|item| item.worry_level().unwrap())
+                    .collect_vec()
+            })
+            .collect_vec();
+        let mut residues = to_residues(&monkeys).unwrap();
+
+        let mut rounds = 0;
+        'simulation: loop {
+            for (index, monkey) in monkeys.iter().enumerate() {
+                for item in std::mem::take(&mut exact[index]) {
+                    let Some(item) = (match monkey.operation {
+                        Operation::MulConst(value) => item.checked_mul(value.into()),
+                        Operation::AddConst(value) => item.checked_add(value.into()),
+                        Operation::MulSelf => item.checked_mul(item),
+                    }) else {
+                        break 'simulation;
+                    };
+
+                    let target = if item % u128::from(monkey.test.get()) == 0 {
+                        monkey.target_true
+                    } else {
+                        monkey.target_false
+                    };
+                    exact[target].push(item);
+                }
+            }
+
+            simulate1(&mut residues, 1.try_into().unwrap());
+            rounds += 1;
+
+            for (exact, monkey) in exact.iter().zip(&residues) {
+                let worry_levels = monkey
+                    .items()
+                    .iter()
+                    .map(|item| item.worry_level().unwrap())
+                    .collect_vec();
+                let expected = exact.iter().map(|item| item % modulus).collect_vec();
+                assert_eq!(worry_levels, expected, "Round {}", rounds);
+            }
+        }
+
+        assert!(rounds >= 3);
+    }
+}
@@ -0,0 +1,157 @@
+//! Day 12: Hill Climbing Algorithm.
+
+use anyhow::{bail, Context, Result};
+use itertools::Itertools;
+use nalgebra::Point2;
+
+use crate::{
+    grid::Grid,
+    search::{bfs_multi, Path},
+    solution::Solution,
+};
+
+pub struct Day12 {
+    map: Map,
+}
+
+impl Day12 {
+    pub fn map(&self) -> &Map {
+        &self.map
+    }
+}
+
+impl Solution for Day12 {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Self {
+            map: parse_input(input)?,
+        })
+    }
+
+    fn part1(&self) -> Result<usize> {
+        let path = self
+            .map
+            .find_shortest_path(self.map.start)
+            .context("No path from the start point")?;
+        Ok(path.cost())
+    }
+
+    fn part2(&self) -> Result<usize> {
+        let lowest_points = self
+            .map
+            .map
+            .iter()
+            .filter(|&(_, &height)| height == b'a')
+            .map(|(point, _)| point);
+        let path = self
+            .map
+            .find_shortest_path_from_any(lowest_points)
+            .context("No path from any of the lowest points")?;
+        Ok(path.cost())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Map {
+    map: Grid<u8>,
+    start: Point2<usize>,
+    end: Point2<usize>,
+}
+
+impl Map {
+    pub fn start(&self) -> Point2<usize> {
+        self.start
+    }
+
+    pub fn end(&self) -> Point2<usize> {
+        self.end
+    }
+
+    pub fn find_shortest_path(&self, start: Point2<usize>) -> Option<Path<Point2<usize>, usize>> {
+        self.find_shortest_path_from_any([start])
+    }
+
+    /// Searches from all the given start points at once, returning the shortest
+    /// of the paths to the end point.
+    pub fn find_shortest_path_from_any(
+        &self,
+        starts: impl IntoIterator<Item = Point2<usize>>,
+    ) -> Option<Path<Point2<usize>, usize>> {
+        bfs_multi(
+            starts,
+            |&point| {
+                self.map
+                    .neighbours4(point)
+                    .filter(move |&destination| self.height_difference(point, destination) >= -1)
+            },
+            |&point| point == self.end,
+        )
+    }
+
+    /// Draws the heightmap with the path overlaid. Every step is replaced with
+    /// an arrow pointing to the next one, and the end of the path with `E`.
+    pub fn render_path(&self, path: &[Point2<usize>]) -> Grid<char> {
+        let mut rendered = self.map.map(|&height| height as char);
+
+        for (from, to) in path.iter().tuple_windows() {
+            rendered[*from] = if to.y < from.y {
+                '^'
+            } else if to.y > from.y {
+                'v'
+            } else if to.x < from.x {
+                '<'
+            } else {
+                '>'
+            };
+        }
+
+        if let Some(&end) = path.last() {
+            rendered[end] = 'E';
+        }
+
+        rendered
+    }
+
+    fn height_difference(&self, a: Point2<usize>, b: Point2<usize>) -> i16 {
+        let height_a: i16 = self.map[a].into();
+        let height_b: i16 = self.map[b].into();
+        height_a - height_b
+    }
+}
+
+fn parse_input(input: &str) -> Result<Map> {
+    let mut map = Grid::parse(input, |c| match c {
+        'a'..='z' | 'S' | 'E' => Some(c as u8),
+        _ => None,
+    })?;
+
+    let mut start = None;
+    let mut end = None;
+
+    for (point, element) in map.iter_mut() {
+        match element {
+            b'S' => {
+                if start.is_some() {
+                    bail!("Expected only one start point");
+                }
+                start = Some(point);
+                *element = b'a';
+            }
+            b'E' => {
+                if end.is_some() {
+                    bail!("Expected only one end point");
+                }
+                end = Some(point);
+                *element = b'z';
+            }
+            _ => {}
+        }
+    }
+
+    let start = start.context("Expected a start point")?;
+    let end = end.context("Expected an end point")?;
+
+    Ok(Map { map, start, end })
+}
@@ -0,0 +1,192 @@
+//! Day 13: Distress Signal.
+
+use std::{fmt::Display, str::FromStr};
+
+use anyhow::{bail, Context, Result};
+use itertools::Itertools;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::line_ending,
+    combinator::{all_consuming, map},
+    multi::separated_list0,
+    sequence::{delimited, separated_pair},
+    IResult,
+};
+
+use crate::{
+    parse::{blocks, parse_all, unsigned},
+    solution::Solution,
+};
+
+pub struct Day13 {
+    packet_pairs: Vec<(Packet, Packet)>,
+}
+
+impl Solution for Day13 {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self> {
+        let packet_pairs = parse_all(
+            input,
+            blocks(separated_pair(parse_packet, line_ending, parse_packet)),
+        )?;
+        Ok(Self { packet_pairs })
+    }
+
+    fn part1(&self) -> Result<usize> {
+        let mut sum_of_indices = 0;
+        for (index, (left, right)) in self.packet_pairs.iter().enumerate() {
+            if left < right {
+                sum_of_indices += index + 1;
+            }
+        }
+        Ok(sum_of_indices)
+    }
+
+    fn part2(&self) -> Result<usize> {
+        let divider1: Packet = "[[2]]".parse().unwrap();
+        let divider2: Packet = "[[6]]".parse().unwrap();
+
+        let packets = self
+            .packet_pairs
+            .iter()
+            .cloned()
+            .chain(std::iter::once((divider1.clone(), divider2.clone())))
+            .flat_map(|(a, b)| [a, b])
+            .sorted()
+            .collect_vec();
+
+        let divider1_index = packets
+            .binary_search(&divider1)
+            .ok()
+            .context("Divider not found")?
+            + 1;
+        let divider2_index = packets
+            .binary_search(&divider2)
+            .ok()
+            .context("Divider not found")?
+            + 1;
+        Ok(divider1_index * divider2_index)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Packet {
+    contents: Vec<PacketElement>,
+}
+
+#[derive(Debug, Clone)]
+pub enum PacketElement {
+    List(Vec<PacketElement>),
+    Int(u32),
+}
+
+impl Display for Packet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[")?;
+
+        if let Some((last, first)) = self.contents.split_last() {
+            for element in first {
+                write!(f, "{},", element)?;
+            }
+            last.fmt(f)?;
+        }
+
+        write!(f, "]")?;
+
+        Ok(())
+    }
+}
+
+impl Display for PacketElement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::List(list) => {
+                write!(f, "[")?;
+
+                if let Some((last, first)) = list.split_last() {
+                    for element in first {
+                        write!(f, "{},", element)?;
+                    }
+                    last.fmt(f)?;
+                }
+
+                write!(f, "]")?;
+
+                Ok(())
+            }
+            Self::Int(int) => write!(f, "{}", int),
+        }
+    }
+}
+
+impl PartialEq for PacketElement {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Int(lhs), Self::Int(rhs)) => lhs.eq(rhs),
+            (Self::List(lhs), Self::List(rhs)) => lhs.eq(rhs),
+
+            (Self::List(_), Self::Int(rhs)) => self.eq(&Self::List(vec![Self::Int(*rhs)])),
+            (Self::Int(lhs), Self::List(_)) => Self::List(vec![Self::Int(*lhs)]).eq(other),
+        }
+    }
+}
+
+impl Eq for PacketElement {}
+
+impl PartialOrd for PacketElement {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PacketElement {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match (self, other) {
+            (Self::Int(lhs), Self::Int(rhs)) => lhs.cmp(rhs),
+            (Self::List(lhs), Self::List(rhs)) => lhs.cmp(rhs),
+
+            (Self::List(_), Self::Int(rhs)) => self.cmp(&Self::List(vec![Self::Int(*rhs)])),
+            (Self::Int(lhs), Self::List(_)) => Self::List(vec![Self::Int(*lhs)]).cmp(other),
+        }
+    }
+}
+
+fn parse_packet(input: &str) -> IResult<&str, Packet> {
+    map(parse_list, |contents| Packet { contents })(input)
+}
+
+fn parse_element(input: &str) -> IResult<&str, PacketElement> {
+    let parse_int = map(unsigned, PacketElement::Int);
+    let parse_list = map(parse_list, PacketElement::List);
+    alt((parse_int, parse_list))(input)
+}
+
+fn parse_list(input: &str) -> IResult<&str, Vec<PacketElement>> {
+    delimited(tag("["), separated_list0(tag(","), parse_element), tag("]"))(input)
+}
+
+impl FromStr for Packet {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let element: PacketElement = s.parse()?;
+        match element {
+            PacketElement::List(list) => Ok(Self { contents: list }),
+            PacketElement::Int(int) => bail!("Expected list, found int: {}", int),
+        }
+    }
+}
+
+impl FromStr for PacketElement {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match all_consuming(parse_element)(s) {
+            Ok((_, element)) => Ok(element),
+            Err(error) => Err(error.to_owned().into()),
+        }
+    }
+}
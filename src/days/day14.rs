@@ -0,0 +1,196 @@
+//! Day 14: Regolith Reservoir.
+
+use std::fmt::Display;
+
+use anyhow::{bail, Result};
+use itertools::Itertools;
+use nalgebra::Point2;
+use nom::{bytes::complete::tag, character::complete::space0, sequence::tuple};
+
+use crate::{
+    grid::{Grid, S, SE, SW},
+    parse::{parse_lines, point_list, unsigned},
+    solution::Solution,
+};
+
+const SAND_SOURCE: Point2<usize> = Point2::new(500, 0);
+
+pub struct Day14 {
+    map: Grid<Tile>,
+}
+
+impl Solution for Day14 {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Self {
+            map: parse_input(input)?,
+        })
+    }
+
+    fn part1(&self) -> Result<usize> {
+        Ok(simulate_until_fall_off(&mut self.map.clone()))
+    }
+
+    fn part2(&self) -> Result<usize> {
+        let new_height = self.map.height() + 2;
+
+        // In the worst case, with no obstructions, sand will fall from the source
+        // and form a pyramid with a base of this width (1, 3, 5, ...)
+        let pyramid_base = 1 + 2 * (new_height - 2);
+
+        // To avoid shifting our map to the right, assume that half of the pyramid
+        // base fits in the existing map.
+        assert!(pyramid_base / 2 <= SAND_SOURCE.x);
+
+        // Now extend the map. Horizontally:
+        // - SAND_SOURCE.x + pyramid_base / 2 is the right-most coordinate in the worst case
+        // - +1 to pad it on the right with a rock floor
+        // - +1 to get the actual width
+        let new_width = self.map.width().max(SAND_SOURCE.x + pyramid_base / 2 + 2);
+
+        // Vertically, with a row of air and then the floor
+        let mut map = Grid::from_fn(new_width, new_height, |point| {
+            if point.y == new_height - 1 {
+                Tile::Rock
+            } else {
+                self.map.get(point).copied().unwrap_or(Tile::Air)
+            }
+        });
+
+        Ok(simulate_until_source_blocked(&mut map))
+    }
+}
+
+pub fn simulate_until_source_blocked(map: &mut Grid<Tile>) -> usize {
+    let mut grains = 0;
+    loop {
+        match simulate_grain_until_rest(map, SAND_SOURCE) {
+            Some(new_position) => {
+                grains += 1;
+                if new_position == SAND_SOURCE {
+                    return grains;
+                }
+            }
+            None => {
+                panic!("Grain {} fell off the map", grains);
+            }
+        }
+    }
+}
+
+/// Simulates the fall of sand until the first grain falls off the map.
+/// Returns the number of grains simulated.
+pub fn simulate_until_fall_off(map: &mut Grid<Tile>) -> usize {
+    let mut grains = 0;
+    loop {
+        if simulate_grain_until_rest(map, SAND_SOURCE).is_none() {
+            return grains;
+        }
+        grains += 1;
+    }
+}
+
+/// Simulates the fall of a single grain of sand, until it comes to rest.
+/// Returns the final resting place, or `None` if the grain went off the map.
+fn simulate_grain_until_rest(
+    map: &mut Grid<Tile>,
+    mut grain: Point2<usize>,
+) -> Option<Point2<usize>> {
+    assert_eq!(map[grain], Tile::Air);
+    map[grain] = Tile::Sand;
+
+    loop {
+        let new_position = simulate_grain_one_step(map, grain);
+        match new_position {
+            None => return None,
+            Some(new_position) => {
+                if new_position == grain {
+                    return Some(grain);
+                } else {
+                    grain = new_position;
+                }
+            }
+        }
+    }
+}
+
+/// Simulates a single fall step of a grain of sand.
+/// Returns the grain's new position, or `None` if it would go off the map.
+fn simulate_grain_one_step(map: &mut Grid<Tile>, grain: Point2<usize>) -> Option<Point2<usize>> {
+    assert_eq!(map[grain], Tile::Sand);
+
+    // The grain tries to fall straight down, then down and to the left,
+    // then down and to the right. Any of these may take it off the map.
+    let mut new_position = grain;
+    for direction in [S, SW, SE] {
+        let destination = map.step(grain, direction)?;
+        if map[destination] == Tile::Air {
+            new_position = destination;
+            break;
+        }
+    }
+
+    map[grain] = Tile::Air;
+    map[new_position] = Tile::Sand;
+
+    Some(new_position)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    Sand,
+    Rock,
+    Air,
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Tile::Sand => write!(f, "o"),
+            Tile::Rock => write!(f, "#"),
+            Tile::Air => write!(f, "."),
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Result<Grid<Tile>> {
+    let paths = parse_lines(
+        input,
+        point_list(unsigned::<usize>, tuple((space0, tag("->"), space0))),
+    )?;
+
+    let max_x = paths.iter().flatten().map(|point| point.x).max().unwrap();
+    let max_y = paths.iter().flatten().map(|point| point.y).max().unwrap();
+
+    let mut result = Grid::from_fn(max_x + 1, max_y + 1, |_| Tile::Air);
+
+    for path in paths {
+        for pair in path.windows(2) {
+            let (a, b) = pair.iter().collect_tuple().unwrap();
+
+            if a.x == b.x {
+                let x = a.x;
+
+                let y_range = if a.y <= b.y { a.y..=b.y } else { b.y..=a.y };
+
+                for y in y_range {
+                    result[Point2::new(x, y)] = Tile::Rock;
+                }
+            } else if a.y == b.y {
+                let y = a.y;
+
+                let x_range = if a.x <= b.x { a.x..=b.x } else { b.x..=a.x };
+
+                for x in x_range {
+                    result[Point2::new(x, y)] = Tile::Rock;
+                }
+            } else {
+                bail!("Path segment must be either horizontal or vertical");
+            }
+        }
+    }
+
+    Ok(result)
+}
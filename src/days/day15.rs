@@ -0,0 +1,220 @@
+//! Day 15: Beacon Exclusion Zone.
+
+use std::{
+    collections::HashSet,
+    ops::{Range, RangeInclusive},
+};
+
+use anyhow::{Context, Result};
+use itertools::Itertools;
+use nalgebra::Point2;
+use nom::{
+    bytes::complete::tag,
+    combinator::map,
+    sequence::{preceded, separated_pair},
+    IResult,
+};
+
+use crate::{
+    parse::{parse_lines, signed},
+    solution::Solution,
+    util::manhattan_distance,
+};
+
+pub struct Day15 {
+    data: Vec<(Point2<i64>, Point2<i64>)>,
+    target_y: i64,
+    min_coord: i64,
+    max_coord: i64,
+}
+
+impl Day15 {
+    /// Replaces the row checked in part 1 and the search area of part 2,
+    /// which differ between the example and the real input.
+    pub fn with_bounds(self, target_y: i64, min_coord: i64, max_coord: i64) -> Self {
+        Self {
+            target_y,
+            min_coord,
+            max_coord,
+            ..self
+        }
+    }
+}
+
+impl Solution for Day15 {
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Self {
+            data: parse_input(input)?,
+            target_y: 2000000,
+            min_coord: 0,
+            max_coord: 4000000,
+        })
+    }
+
+    fn part1(&self) -> Result<i64> {
+        let positions_without_beacon = find_positions_without_beacon(&self.data, self.target_y)
+            .into_iter()
+            .map(|range| {
+                if range.is_empty() {
+                    0
+                } else {
+                    range.end - range.start
+                }
+            })
+            .sum();
+        Ok(positions_without_beacon)
+    }
+
+    fn part2(&self) -> Result<i64> {
+        let mut possible_locations = HashSet::new();
+        for y in self.min_coord..=self.max_coord {
+            let without_beacon = find_positions_without_beacon(&self.data, y);
+            let possible_ranges = complement_ranges(&without_beacon);
+            for range in possible_ranges {
+                for x in range_intersection(range, self.min_coord..=self.max_coord) {
+                    possible_locations.insert(Point2::new(x, y));
+                }
+            }
+        }
+
+        for (_, beacon) in &self.data {
+            possible_locations.remove(beacon);
+        }
+
+        let distress_beacon = possible_locations
+            .into_iter()
+            .exactly_one()
+            .ok()
+            .context("Expected exactly one location for distress beacon")?;
+        let tuning_frequency = distress_beacon.x * 4000000 + distress_beacon.y;
+        Ok(tuning_frequency)
+    }
+}
+
+pub fn find_positions_without_beacon(
+    data: &[(Point2<i64>, Point2<i64>)],
+    target_y: i64,
+) -> Vec<Range<i64>> {
+    // Find the range of X coordinates that each sensor says
+    // cannot contain a beacon
+    let mut ranges = data
+        .iter()
+        .filter_map(|(sensor, beacon)| {
+            let radius = manhattan_distance(sensor, beacon);
+
+            let vertical_distance = manhattan_distance(sensor, &Point2::new(sensor.x, target_y));
+
+            if vertical_distance <= radius {
+                let max_horizontal_distance = radius - vertical_distance;
+
+                let mut min_x = sensor.x - max_horizontal_distance;
+                let mut max_x = sensor.x + max_horizontal_distance;
+                if beacon.y == target_y {
+                    if beacon.x == min_x {
+                        min_x += 1;
+                    }
+                    if beacon.x == max_x {
+                        max_x -= 1;
+                    }
+                }
+
+                let range = min_x..max_x + 1;
+                assert!(!(beacon.y == target_y && range.contains(&beacon.x)));
+
+                Some(range)
+            } else {
+                None
+            }
+        })
+        .sorted_by_key(|range| range.start)
+        .collect_vec();
+
+    // Merge overlapping ranges
+    let mut i = 0;
+    while i < ranges.len() - 1 {
+        if ranges[i].contains(&ranges[i + 1].start) {
+            ranges[i] = merge_ranges(ranges[i].clone(), ranges[i + 1].clone());
+            ranges.remove(i + 1);
+        } else {
+            i += 1;
+        }
+    }
+
+    ranges
+}
+
+/// Merges two overlapping ranges.
+/// Panics if the ranges do not overlap.
+fn merge_ranges(first: Range<i64>, second: Range<i64>) -> Range<i64> {
+    if first.start > second.start {
+        return merge_ranges(second, first);
+    }
+
+    assert!(first.contains(&second.start));
+
+    if second.end >= first.end {
+        first.start..second.end
+    } else {
+        first
+    }
+}
+
+/// Given a sequence of non-overlapping ranges, returns a sequence
+/// of ranges that cover the spaces "in-between".
+///
+/// The results are unspecified if any of the ranges overlap.
+fn complement_ranges(ranges: &[Range<i64>]) -> Vec<RangeInclusive<i64>> {
+    if ranges.is_empty() {
+        vec![i64::MIN..=i64::MAX]
+    } else {
+        let ranges = ranges
+            .iter()
+            .sorted_by_key(|range| range.start)
+            .collect_vec();
+
+        ranges
+            .windows(2)
+            .map(|pair| {
+                assert_eq!(pair.len(), 2);
+                assert!(!pair[0].contains(&pair[1].start));
+                pair[0].end..=pair[1].start - 1
+            })
+            .chain([
+                i64::MIN..=ranges.first().unwrap().start - 1,
+                ranges.last().unwrap().end..=i64::MAX,
+            ])
+            .collect()
+    }
+}
+
+fn range_intersection(
+    first: RangeInclusive<i64>,
+    second: RangeInclusive<i64>,
+) -> RangeInclusive<i64> {
+    *first.start().max(second.start())..=*first.end().min(second.end())
+}
+
+fn parse_input(input: &str) -> Result<Vec<(Point2<i64>, Point2<i64>)>> {
+    fn parse_coordinates(input: &str) -> IResult<&str, Point2<i64>> {
+        map(
+            separated_pair(
+                preceded(tag("x="), signed),
+                tag(", "),
+                preceded(tag("y="), signed),
+            ),
+            |(x, y)| Point2::new(x, y),
+        )(input)
+    }
+
+    Ok(parse_lines(
+        input,
+        separated_pair(
+            preceded(tag("Sensor at "), parse_coordinates),
+            tag(": closest beacon is at "),
+            parse_coordinates,
+        ),
+    )?)
+}
@@ -0,0 +1,509 @@
+//! Day 16: Proboscidea Volcanium.
+
+use std::{collections::HashMap, fmt::Display, str::FromStr};
+
+use anyhow::{bail, Context, Result};
+use itertools::{iproduct, Itertools};
+use lazy_static::lazy_static;
+use petgraph::{
+    algo::floyd_warshall,
+    dot::{Config, Dot},
+    graph::{DiGraph, EdgeReference, NodeIndex, UnGraph},
+    graphmap::DiGraphMap,
+    visit::EdgeRef,
+    Direction,
+};
+use regex::Regex;
+
+use crate::{parse::map_lines, solution::Solution};
+
+pub struct Day16 {
+    valves: Vec<Valve>,
+    agents: usize,
+}
+
+impl Day16 {
+    pub fn valves(&self) -> &[Valve] {
+        &self.valves
+    }
+
+    /// Replaces the number of agents opening valves in part 2.
+    pub fn with_agents(self, agents: usize) -> Self {
+        Self { agents, ..self }
+    }
+}
+
+impl Solution for Day16 {
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Self {
+            valves: map_lines(input, str::parse)?,
+            agents: 2,
+        })
+    }
+
+    fn part1(&self) -> Result<u32> {
+        Ok(plan_valves(&self.valves, 30, 1, "AA")?.pressure())
+    }
+
+    fn part2(&self) -> Result<u32> {
+        Ok(plan_valves(&self.valves, 26, self.agents, "AA")?.pressure())
+    }
+
+    fn to_dot(&self) -> Option<Result<String>> {
+        Some(valve_map_to_dot(&self.valves, "AA"))
+    }
+}
+
+/// Which agent opens which valve, and when.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Schedule {
+    pressure: u32,
+    openings: Vec<Opening>,
+}
+
+impl Schedule {
+    /// How much pressure will have been released when time runs out.
+    pub fn pressure(&self) -> u32 {
+        self.pressure
+    }
+
+    /// The valves opened, in chronological order.
+    pub fn openings(&self) -> &[Opening] {
+        &self.openings
+    }
+}
+
+impl Display for Schedule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for opening in &self.openings {
+            writeln!(
+                f,
+                "Minute {}: agent {} opens valve {}",
+                opening.minute,
+                opening.agent + 1,
+                opening.valve
+            )?;
+        }
+        write!(f, "Total pressure released: {}", self.pressure)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Opening {
+    agent: usize,
+    valve: String,
+    minute: u32,
+}
+
+impl Opening {
+    /// The agent opening the valve, counted from 0.
+    pub fn agent(&self) -> usize {
+        self.agent
+    }
+
+    pub fn valve(&self) -> &str {
+        &self.valve
+    }
+
+    /// The minute during which the valve is opened, counted from 1.
+    /// It starts releasing pressure in the following minute.
+    pub fn minute(&self) -> u32 {
+        self.minute
+    }
+}
+
+/// A sequence of valves opened by a single agent, each along with the minute
+/// it's opened in.
+#[derive(Debug, Clone, Default)]
+struct Route {
+    pressure: u32,
+    openings: Vec<(NodeIndex, u32)>,
+}
+
+/// The most working valves [`plan_valves`] can handle. It keeps several tables
+/// with an entry for every set of working valves, so each one more doubles them.
+pub const MAX_WORKING_VALVES: usize = 24;
+
+/// Finds the schedule that releases the most pressure, with the given number of
+/// agents all setting off from the start valve at once.
+///
+/// First, for every set of valves, this finds the best route for a single agent
+/// that opens exactly those valves. The sets are then split between the agents,
+/// one agent at a time: the best way for `n` agents to open valves from a set
+/// is the best single route within some subset, plus the best way for `n - 1`
+/// agents to open valves from the rest.
+///
+/// Fails if the start valve isn't found exactly once, or if there are more than
+/// [`MAX_WORKING_VALVES`] working valves.
+pub fn plan_valves(
+    valves: &[Valve],
+    total_time: u32,
+    agents: usize,
+    start_valve: &str,
+) -> Result<Schedule> {
+    // First, build a map of the tunnel system
+    let (map, start) = compress_valve_map(valves, start_valve)?;
+
+    // The working valves come first in the map, so their node indices
+    // double as their bits in a set of valves
+    let working = map
+        .node_weights()
+        .filter(|valve| valve.flow_rate != 0)
+        .count();
+    if working > MAX_WORKING_VALVES {
+        bail!(
+            "There are {} working valves, but at most {} are supported",
+            working,
+            MAX_WORKING_VALVES
+        );
+    }
+    let all = (1usize << working) - 1;
+
+    let mut routes = vec![None; all + 1];
+    explore_routes(
+        &map,
+        total_time,
+        start,
+        0,
+        0,
+        &mut Route::default(),
+        &mut routes,
+    );
+    let pressure = |set: usize| {
+        routes[set]
+            .as_ref()
+            .map_or(0, |route: &Route| route.pressure)
+    };
+
+    // For every set of valves, the subset opened by the best single route within it
+    let mut best_within = (0..=all)
+        .map(|set| if routes[set].is_some() { set } else { 0 })
+        .collect_vec();
+    for bit in 0..working {
+        for set in 0..=all {
+            if set & (1 << bit) != 0 {
+                let without = best_within[set ^ (1 << bit)];
+                if pressure(without) > pressure(best_within[set]) {
+                    best_within[set] = without;
+                }
+            }
+        }
+    }
+
+    // The most pressure the agents planned so far can release using only
+    // the valves in each set, and the subset given to the last of them
+    let mut most = (0..=all)
+        .map(|set| pressure(best_within[set]))
+        .collect_vec();
+    let mut choices = vec![(0..=all).collect_vec()];
+    for _ in 1..agents {
+        let mut next_most = vec![0; all + 1];
+        let mut choice = vec![0; all + 1];
+
+        for set in 0..=all {
+            // Go over all subsets of the set, the empty one included
+            let mut subset = set;
+            loop {
+                let total = pressure(best_within[subset]) + most[set ^ subset];
+                if total > next_most[set] {
+                    next_most[set] = total;
+                    choice[set] = subset;
+                }
+
+                if subset == 0 {
+                    break;
+                }
+                subset = (subset - 1) & set;
+            }
+        }
+
+        most = next_most;
+        choices.push(choice);
+    }
+
+    // Now hand out the valves, starting from the last agent planned
+    let mut openings = vec![];
+    let mut remaining = all;
+    for (agent, choice) in choices.iter().enumerate().take(agents).rev() {
+        let subset = choice[remaining];
+        remaining ^= subset;
+
+        if let Some(route) = &routes[best_within[subset]] {
+            openings.extend(route.openings.iter().map(|&(valve, minute)| Opening {
+                agent,
+                valve: map[valve].name.clone(),
+                minute,
+            }));
+        }
+    }
+    openings.sort_by_key(|opening| (opening.minute, opening.agent));
+
+    Ok(Schedule {
+        pressure: if agents == 0 { 0 } else { most[all] },
+        openings,
+    })
+}
+
+/// Goes over every route a single agent can take from the given position,
+/// keeping the best route for each set of opened valves.
+fn explore_routes(
+    map: &DiGraph<&Valve, u32>,
+    total_time: u32,
+    position: NodeIndex,
+    time: u32,
+    turned_on_valves: usize,
+    route: &mut Route,
+    best: &mut [Option<Route>],
+) {
+    if best[turned_on_valves]
+        .as_ref()
+        .is_none_or(|best| route.pressure > best.pressure)
+    {
+        best[turned_on_valves] = Some(route.clone());
+    }
+
+    for edge in map.edges_directed(position, Direction::Outgoing) {
+        // Is this valve already turned on?
+        let valve = edge.target();
+        if turned_on_valves & (1 << valve.index()) != 0 {
+            continue;
+        }
+
+        // No sense in turning on valves that don't contribute anything
+        let flow_rate = map[valve].flow_rate;
+        if flow_rate == 0 {
+            continue;
+        }
+
+        // New time is after moving to the new valve and turning it on
+        let new_time = time + edge.weight() + 1;
+        if new_time >= total_time {
+            continue;
+        }
+
+        route.pressure += (total_time - new_time) * flow_rate;
+        route.openings.push((valve, new_time));
+
+        explore_routes(
+            map,
+            total_time,
+            valve,
+            new_time,
+            turned_on_valves | (1 << valve.index()),
+            route,
+            best,
+        );
+
+        route.openings.pop();
+        route.pressure -= (total_time - new_time) * flow_rate;
+    }
+}
+
+/// Renders the map the valves are planned on in Graphviz DOT format: just the
+/// working valves and the start, with every pair of them joined by an edge
+/// labeled with the number of minutes it takes to get from one to the other.
+///
+/// Fails if the start valve isn't found exactly once.
+pub fn valve_map_to_dot(valves: &[Valve], start_valve: &str) -> Result<String> {
+    let (map, start) = compress_valve_map(valves, start_valve)?;
+
+    // Distances are the same both ways, so draw each pair of valves only once
+    let mut undirected = UnGraph::new_undirected();
+    for &valve in map.node_weights() {
+        undirected.add_node(valve);
+    }
+    for edge in map.edge_references() {
+        if edge.source() < edge.target() {
+            undirected.add_edge(edge.source(), edge.target(), *edge.weight());
+        }
+    }
+
+    let node_attributes = |_, (node, valve): (NodeIndex, &&Valve)| {
+        let label = format!("{}\nflow rate={}", valve.name, valve.flow_rate);
+        let shape = if node == start {
+            "doublecircle"
+        } else {
+            "circle"
+        };
+        format!("label = {:?}, shape = {}", label, shape)
+    };
+
+    let edge_attributes = |_, edge: EdgeReference<u32>| format!("label = \"{}\"", edge.weight());
+
+    let dot = Dot::with_attr_getters(
+        &undirected,
+        &[Config::NodeNoLabel, Config::EdgeNoLabel],
+        &edge_attributes,
+        &node_attributes,
+    );
+    Ok(format!("{:?}", dot))
+}
+
+fn compress_valve_map<'a>(
+    valves: &'a [Valve],
+    start_valve: &str,
+) -> Result<(DiGraph<&'a Valve, u32>, NodeIndex)> {
+    // Directed graph because `floyd_warshall` doesn't like undirected ones...
+    let mut map: DiGraphMap<&str, ()> = DiGraphMap::new();
+    for valve in valves {
+        for neighbour in &valve.tunnels {
+            map.add_edge(&valve.name, neighbour, ());
+        }
+    }
+
+    // We only care about the working valves, and the starting one,
+    // so let's keep just them. The working valves go first.
+
+    let mut compressed = DiGraph::new();
+
+    let shortest_paths = floyd_warshall(&map, |_| 1u32).unwrap();
+
+    let interesting_valves_indices = valves
+        .iter()
+        .enumerate()
+        .filter_map(|(index, valve)| {
+            if valve.name == start_valve || valve.flow_rate != 0 {
+                Some(index)
+            } else {
+                None
+            }
+        })
+        .sorted_by_key(|&index| valves[index].flow_rate == 0)
+        .collect_vec();
+
+    let valve_index_to_node_index: HashMap<_, _> = interesting_valves_indices
+        .iter()
+        .map(|&valve_index| {
+            let node_index = compressed.add_node(&valves[valve_index]);
+            (valve_index, node_index)
+        })
+        .collect();
+
+    for (&a, &b) in iproduct!(&interesting_valves_indices, &interesting_valves_indices) {
+        if a == b {
+            continue;
+        }
+
+        let distance = shortest_paths[&(valves[a].name.as_str(), valves[b].name.as_str())];
+
+        if distance == u32::MAX {
+            continue;
+        }
+
+        compressed.add_edge(
+            valve_index_to_node_index[&a],
+            valve_index_to_node_index[&b],
+            distance,
+        );
+    }
+
+    let start = interesting_valves_indices
+        .into_iter()
+        .filter(|&index| valves[index].name == start_valve)
+        .exactly_one()
+        .ok()
+        .with_context(|| format!("Expected to find exactly one start valve '{}'", start_valve))?;
+    let start = valve_index_to_node_index[&start];
+
+    Ok((compressed, start))
+}
+
+#[derive(Debug, Clone)]
+pub struct Valve {
+    name: String,
+    flow_rate: u32,
+    tunnels: Vec<String>,
+}
+
+impl FromStr for Valve {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref REGEX: Regex = Regex::new(
+                r#"^Valve ([A-Z]{2}) has flow rate=(\d+); tunnels? leads? to valves? ([A-Z]{2}(?:, [A-Z]{2})*)$"#
+            )
+            .unwrap();
+        }
+
+        let captures = REGEX.captures(s).context("Invalid valve descriptor")?;
+
+        let name = captures.get(1).unwrap().as_str().to_owned();
+
+        let flow_rate = captures.get(2).unwrap().as_str().parse()?;
+
+        let tunnels = captures
+            .get(3)
+            .unwrap()
+            .as_str()
+            .split(", ")
+            .map(str::to_owned)
+            .collect();
+
+        Ok(Self {
+            name,
+            flow_rate,
+            tunnels,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::{plan_valves, valve_map_to_dot, Valve, MAX_WORKING_VALVES};
+
+    /// A row of tunnels with a working valve at every step past the start.
+    fn corridor(working: usize) -> Vec<Valve> {
+        let names = (0..=working)
+            .map(|index| {
+                let letter = |value: usize| char::from(b'A' + value as u8);
+                format!("{}{}", letter(index / 26), letter(index % 26))
+            })
+            .collect_vec();
+        names
+            .iter()
+            .enumerate()
+            .map(|(index, name)| {
+                let neighbours = [index.checked_sub(1), Some(index + 1)]
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|neighbour| names.get(neighbour))
+                    .join(", ");
+                let flow_rate = if index == 0 { 0 } else { 1 };
+                format!(
+                    "Valve {} has flow rate={}; tunnels lead to valves {}",
+                    name, flow_rate, neighbours
+                )
+                .parse()
+                .unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn too_many_working_valves() {
+        assert!(plan_valves(&corridor(MAX_WORKING_VALVES + 1), 30, 1, "AA").is_err());
+
+        // One agent opens the first and third valves, at minutes 2 and 5,
+        // and the other the second and fourth, at minutes 3 and 6
+        assert_eq!(
+            plan_valves(&corridor(4), 10, 2, "AA").unwrap().pressure(),
+            8 + 7 + 5 + 4
+        );
+    }
+
+    #[test]
+    fn missing_start_valve() {
+        let valves = corridor(3);
+        assert!(plan_valves(&valves, 30, 1, "ZZ").is_err());
+        assert!(valve_map_to_dot(&valves, "ZZ").is_err());
+
+        let duplicated = [valves.clone(), valves].concat();
+        assert!(plan_valves(&duplicated, 30, 1, "AA").is_err());
+    }
+}
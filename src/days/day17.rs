@@ -0,0 +1,230 @@
+//! Day 17: Pyroclastic Flow.
+
+use std::collections::HashMap;
+
+use anyhow::Result;
+use nom::{branch::alt, character::complete::char, combinator::value, multi::many1};
+
+use crate::{parse::parse_all, solution::Solution};
+
+const CHAMBER_WIDTH: usize = 7;
+
+pub struct Day17 {
+    jets: Vec<Jet>,
+}
+
+impl Solution for Day17 {
+    type Part1 = usize;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self> {
+        let jets = parse_all(
+            input,
+            many1(alt((
+                value(Jet::Left, char('<')),
+                value(Jet::Right, char('>')),
+            ))),
+        )?;
+        Ok(Self { jets })
+    }
+
+    fn part1(&self) -> Result<usize> {
+        let mut chamber = Chamber::new();
+        chamber.simulate(&self.jets, 2022);
+        Ok(chamber.height())
+    }
+
+    fn part2(&self) -> Result<u64> {
+        Ok(tower_height(&self.jets, 1_000_000_000_000))
+    }
+}
+
+/// Computes the height of the tower after the given number of rocks
+/// have come to rest.
+///
+/// Instead of simulating every rock, this looks for a repeating state
+/// of the chamber and skips over as many full cycles as possible.
+pub fn tower_height(jets: &[Jet], rocks: u64) -> u64 {
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    struct State {
+        rock_index: usize,
+        jet_index: usize,
+        skyline: [usize; CHAMBER_WIDTH],
+    }
+
+    let mut chamber = Chamber::new();
+
+    // Maps a state to the number of rocks dropped and the tower height
+    // at the time the state was first seen
+    let mut seen: HashMap<State, (u64, u64)> = HashMap::new();
+
+    for dropped in 0..rocks {
+        let state = State {
+            rock_index: chamber.rock_index,
+            jet_index: chamber.jet_index,
+            skyline: chamber.skyline(),
+        };
+        let height: u64 = chamber.height().try_into().unwrap();
+
+        if let Some((cycle_start, height_at_start)) = seen.insert(state, (dropped, height)) {
+            let cycle_length = dropped - cycle_start;
+            let cycle_height = height - height_at_start;
+
+            let remaining = rocks - dropped;
+            let cycles = remaining / cycle_length;
+
+            chamber.simulate(jets, (remaining % cycle_length).try_into().unwrap());
+            let height: u64 = chamber.height().try_into().unwrap();
+
+            return height + cycles * cycle_height;
+        }
+
+        chamber.drop_rock(jets);
+    }
+
+    chamber.height().try_into().unwrap()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Jet {
+    Left,
+    Right,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rock {
+    /// Rows of the rock, bottom to top. Bit N is set if column N
+    /// (counting from the left edge of the rock) is occupied.
+    rows: &'static [u8],
+    width: usize,
+}
+
+const ROCKS: [Rock; 5] = [
+    // ####
+    Rock {
+        rows: &[0b1111],
+        width: 4,
+    },
+    // .#.
+    // ###
+    // .#.
+    Rock {
+        rows: &[0b010, 0b111, 0b010],
+        width: 3,
+    },
+    // ..#
+    // ..#
+    // ###
+    Rock {
+        rows: &[0b111, 0b100, 0b100],
+        width: 3,
+    },
+    // #
+    // #
+    // #
+    // #
+    Rock {
+        rows: &[0b1, 0b1, 0b1, 0b1],
+        width: 1,
+    },
+    // ##
+    // ##
+    Rock {
+        rows: &[0b11, 0b11],
+        width: 2,
+    },
+];
+
+#[derive(Debug, Clone, Default)]
+pub struct Chamber {
+    /// Rows of settled rock, bottom to top, in the same format
+    /// as [`Rock::rows`]. There are never any empty rows at the top.
+    rows: Vec<u8>,
+    rock_index: usize,
+    jet_index: usize,
+}
+
+impl Chamber {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn simulate(&mut self, jets: &[Jet], rocks: usize) {
+        for _ in 0..rocks {
+            self.drop_rock(jets);
+        }
+    }
+
+    /// Drops the next rock into the chamber and lets it come to rest.
+    pub fn drop_rock(&mut self, jets: &[Jet]) {
+        let rock = ROCKS[self.rock_index];
+        self.rock_index = (self.rock_index + 1) % ROCKS.len();
+
+        // Each rock appears so that its left edge is two units away from the left wall
+        // and its bottom edge is three units above the highest rock in the room
+        let mut x: usize = 2;
+        let mut y = self.height() + 3;
+
+        loop {
+            let jet = jets[self.jet_index];
+            self.jet_index = (self.jet_index + 1) % jets.len();
+
+            let pushed_x = match jet {
+                Jet::Left => x.checked_sub(1),
+                Jet::Right => {
+                    if x + rock.width < CHAMBER_WIDTH {
+                        Some(x + 1)
+                    } else {
+                        None
+                    }
+                }
+            };
+            if let Some(pushed_x) = pushed_x {
+                if !self.collides(&rock, pushed_x, y) {
+                    x = pushed_x;
+                }
+            }
+
+            if y == 0 || self.collides(&rock, x, y - 1) {
+                break;
+            }
+            y -= 1;
+        }
+
+        for (offset, &row) in rock.rows.iter().enumerate() {
+            if y + offset >= self.rows.len() {
+                self.rows.push(0);
+            }
+            self.rows[y + offset] |= row << x;
+        }
+    }
+
+    fn collides(&self, rock: &Rock, x: usize, y: usize) -> bool {
+        rock.rows.iter().enumerate().any(|(offset, &row)| {
+            self.rows
+                .get(y + offset)
+                .is_some_and(|&settled| settled & (row << x) != 0)
+        })
+    }
+
+    /// Returns, for each column, the distance from the top of the tower
+    /// to the highest occupied cell in that column.
+    pub fn skyline(&self) -> [usize; CHAMBER_WIDTH] {
+        let mut skyline = [self.height(); CHAMBER_WIDTH];
+        for (column, depth) in skyline.iter_mut().enumerate() {
+            if let Some(distance) = self
+                .rows
+                .iter()
+                .rev()
+                .position(|&row| row & (1 << column) != 0)
+            {
+                *depth = distance;
+            }
+        }
+        skyline
+    }
+}
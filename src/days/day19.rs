@@ -0,0 +1,315 @@
+//! Day 19: Not Enough Minerals.
+
+use std::{
+    collections::HashMap,
+    ops::{Add, Sub},
+};
+
+use anyhow::{bail, Context, Result};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::{parse::map_lines, search::branch_and_bound, solution::Solution};
+
+pub struct Day19 {
+    blueprints: Vec<Blueprint>,
+}
+
+impl Day19 {
+    pub fn blueprints(&self) -> &[Blueprint] {
+        &self.blueprints
+    }
+}
+
+impl Solution for Day19 {
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Self {
+            blueprints: parse_input(input)?,
+        })
+    }
+
+    fn part1(&self) -> Result<u32> {
+        let quality_levels = self
+            .blueprints
+            .iter()
+            .enumerate()
+            .map(|(index, blueprint)| {
+                let id: u32 = index.try_into().unwrap();
+                maximize_geodes(blueprint, 24) * (id + 1)
+            })
+            .sum();
+        Ok(quality_levels)
+    }
+
+    fn part2(&self) -> Result<u32> {
+        let geodes_product = self
+            .blueprints
+            .iter()
+            .take(3)
+            .map(|blueprint| maximize_geodes(blueprint, 32))
+            .product();
+        Ok(geodes_product)
+    }
+}
+
+pub fn maximize_geodes(blueprint: &Blueprint, total_time: u32) -> u32 {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    struct Node {
+        resources: Resources,
+        robots: Robots,
+        time: u32,
+    }
+
+    // We can only spend so much of each resource in a single minute,
+    // so there's no point in collecting more than that per minute.
+    // Geode robots are the exception, since geodes are what we're after.
+    let max_robots = Robots {
+        ore: blueprint
+            .ore
+            .ore
+            .max(blueprint.clay.ore)
+            .max(blueprint.obsidian.ore)
+            .max(blueprint.geode.ore),
+        clay: blueprint.obsidian.clay,
+        obsidian: blueprint.geode.obsidian,
+        geode: u32::MAX,
+    };
+
+    let start = Node {
+        resources: Resources::default(),
+        robots: Robots {
+            ore: 1,
+            ..Default::default()
+        },
+        time: 0,
+    };
+
+    let neighbours = |&node: &Node| {
+        let build = [
+            (
+                blueprint.ore,
+                node.robots.ore < max_robots.ore,
+                Robots {
+                    ore: 1,
+                    ..Default::default()
+                },
+            ),
+            (
+                blueprint.clay,
+                node.robots.clay < max_robots.clay,
+                Robots {
+                    clay: 1,
+                    ..Default::default()
+                },
+            ),
+            (
+                blueprint.obsidian,
+                node.robots.obsidian < max_robots.obsidian,
+                Robots {
+                    obsidian: 1,
+                    ..Default::default()
+                },
+            ),
+            (
+                blueprint.geode,
+                node.robots.geode < max_robots.geode,
+                Robots {
+                    geode: 1,
+                    ..Default::default()
+                },
+            ),
+        ];
+
+        // Instead of deciding what to do every minute, decide which robot to build
+        // next, and skip ahead to the minute when it's done
+        build
+            .into_iter()
+            .filter(|&(_, needed, _)| needed)
+            .filter_map(move |(price, _, robot)| {
+                let wait = node.resources.time_until_enough_for(&price, &node.robots)?;
+
+                // A robot finished on the very last minute won't collect anything
+                let time = node.time + wait + 1;
+                if time >= total_time {
+                    return None;
+                }
+
+                Some(Node {
+                    resources: node.resources.collect(&node.robots, wait + 1) - price,
+                    robots: node.robots + robot,
+                    time,
+                })
+            })
+    };
+
+    // If we stop building robots now, this is what we'll end up with
+    let geodes = |node: &Node| {
+        let remaining = total_time - node.time;
+        node.resources.geode + node.robots.geode * remaining
+    };
+
+    // Even if we could build a geode robot every remaining minute,
+    // this is the most we could get
+    let upper_bound = |node: &Node| {
+        let remaining = total_time - node.time;
+        geodes(node) + remaining * remaining.saturating_sub(1) / 2
+    };
+
+    branch_and_bound(start, neighbours, geodes, upper_bound).cost()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Blueprint {
+    ore: Price,
+    clay: Price,
+    obsidian: Price,
+    geode: Price,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Price {
+    ore: u32,
+    clay: u32,
+    obsidian: u32,
+    geode: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Resources {
+    ore: u32,
+    clay: u32,
+    obsidian: u32,
+    geode: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Robots {
+    ore: u32,
+    clay: u32,
+    obsidian: u32,
+    geode: u32,
+}
+
+impl Add for Robots {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            ore: self.ore + rhs.ore,
+            clay: self.clay + rhs.clay,
+            obsidian: self.obsidian + rhs.obsidian,
+            geode: self.geode + rhs.geode,
+        }
+    }
+}
+
+impl Sub<Price> for Resources {
+    type Output = Self;
+
+    fn sub(self, rhs: Price) -> Self::Output {
+        Self {
+            ore: self.ore - rhs.ore,
+            clay: self.clay - rhs.clay,
+            obsidian: self.obsidian - rhs.obsidian,
+            geode: self.geode - rhs.geode,
+        }
+    }
+}
+
+impl Resources {
+    /// Returns the number of minutes the given robots need to collect enough
+    /// resources for the price, or `None` if they will never collect enough.
+    pub fn time_until_enough_for(&self, price: &Price, robots: &Robots) -> Option<u32> {
+        fn time_until(have: u32, need: u32, rate: u32) -> Option<u32> {
+            if have >= need {
+                Some(0)
+            } else if rate == 0 {
+                None
+            } else {
+                Some((need - have).div_ceil(rate))
+            }
+        }
+
+        [
+            time_until(self.ore, price.ore, robots.ore)?,
+            time_until(self.clay, price.clay, robots.clay)?,
+            time_until(self.obsidian, price.obsidian, robots.obsidian)?,
+            time_until(self.geode, price.geode, robots.geode)?,
+        ]
+        .into_iter()
+        .max()
+    }
+
+    /// Returns the resources after the given robots have been collecting
+    /// for the given number of minutes.
+    pub fn collect(&self, robots: &Robots, minutes: u32) -> Self {
+        Self {
+            ore: self.ore + robots.ore * minutes,
+            clay: self.clay + robots.clay * minutes,
+            obsidian: self.obsidian + robots.obsidian * minutes,
+            geode: self.geode + robots.geode * minutes,
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Blueprint>> {
+    Ok(map_lines(input, |line| {
+        lazy_static! {
+            static ref REGEX: Regex = Regex::new(r#"Each (ore|clay|obsidian|geode) robot costs (\d+) (ore|clay|obsidian)(?: and (\d+) (ore|clay|obsidian)|)\."#).unwrap();
+        }
+
+        let mut robots = HashMap::new();
+
+        for robot in REGEX.captures_iter(line) {
+            let kind = robot.get(1).unwrap().as_str();
+
+            let cost1: u32 = robot.get(2).unwrap().as_str().parse()?;
+            let material1 = robot.get(3).unwrap().as_str();
+
+            let (cost2, material2) = if let Some(cost2) = robot.get(4) {
+                let cost2: u32 = cost2.as_str().parse()?;
+                let material2 = robot.get(5).unwrap().as_str();
+                (cost2, material2)
+            } else {
+                (0, "")
+            };
+
+            if material1 == material2 {
+                bail!("Expected two different material costs");
+            }
+
+            let mut price = Price::default();
+
+            match material1 {
+                "ore" => price.ore = cost1,
+                "clay" => price.clay = cost1,
+                "obsidian" => price.obsidian = cost1,
+                _ => unreachable!(),
+            }
+
+            match material2 {
+                "ore" => price.ore = cost2,
+                "clay" => price.clay = cost2,
+                "obsidian" => price.obsidian = cost2,
+                "" => {}
+                _ => unreachable!(),
+            }
+
+            robots.insert(kind.to_owned(), price);
+        }
+
+        let blueprint = Blueprint {
+            ore: *robots.get("ore").context("Didn't find ore robot")?,
+            clay: *robots.get("clay").context("Didn't find clay robot")?,
+            obsidian: *robots
+                .get("obsidian")
+                .context("Didn't find obsidian robot")?,
+            geode: *robots.get("geode").context("Didn't find geode robot")?,
+        };
+
+        Ok(blueprint)
+    })?)
+}
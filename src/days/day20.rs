@@ -0,0 +1,150 @@
+//! Day 20: Grove Positioning System.
+
+use std::iter;
+
+use anyhow::Result;
+use itertools::Itertools;
+
+use crate::{
+    parse::{parse_lines, signed},
+    solution::Solution,
+};
+
+const DECRYPTION_KEY: i64 = 811589153;
+
+pub struct Day20 {
+    numbers: Vec<i64>,
+}
+
+impl Solution for Day20 {
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Self {
+            numbers: parse_lines(input, signed)?,
+        })
+    }
+
+    fn part1(&self) -> Result<i64> {
+        let (a, b, c) = compute_coordinates(&self.numbers, 1);
+        Ok(a + b + c)
+    }
+
+    fn part2(&self) -> Result<i64> {
+        let numbers = self
+            .numbers
+            .iter()
+            .map(|number| number * DECRYPTION_KEY)
+            .collect_vec();
+        let (a, b, c) = compute_coordinates(&numbers, 10);
+        Ok(a + b + c)
+    }
+}
+
+pub fn compute_coordinates(numbers: &[i64], rounds: u32) -> (i64, i64, i64) {
+    let mut mixed_numbers = mix(numbers, rounds).cycle();
+
+    mixed_numbers.find(|&value| value == 0).unwrap();
+
+    let first = mixed_numbers.nth(1000 - 1).unwrap();
+    let second = mixed_numbers.nth(1000 - 1).unwrap();
+    let third = mixed_numbers.nth(1000 - 1).unwrap();
+
+    (first, second, third)
+}
+
+pub fn mix(numbers: &[i64], rounds: u32) -> impl Iterator<Item = i64> + Clone {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct Entry {
+        value: i64,
+        next: usize,
+        prev: usize,
+    }
+
+    let mut list = numbers
+        .iter()
+        .enumerate()
+        .map(|(index, &number)| Entry {
+            value: number,
+            next: if index == numbers.len() - 1 {
+                0
+            } else {
+                index + 1
+            },
+            prev: if index == 0 {
+                numbers.len() - 1
+            } else {
+                index - 1
+            },
+        })
+        .collect_vec();
+
+    fn next(list: &[Entry], entry: usize, count: usize) -> usize {
+        let mut remaining = count;
+        let mut current = entry;
+        while remaining > 0 {
+            current = list[current].next;
+            remaining -= 1;
+        }
+        current
+    }
+
+    fn prev(list: &[Entry], entry: usize, count: usize) -> usize {
+        let mut remaining = count;
+        let mut current = entry;
+        while remaining > 0 {
+            current = list[current].prev;
+            remaining -= 1;
+        }
+        current
+    }
+
+    fn insert_after(list: &mut [Entry], entry: usize, prev: usize) {
+        assert_ne!(entry, prev);
+
+        let next = list[prev].next;
+
+        list[entry].next = next;
+        list[entry].prev = prev;
+
+        list[prev].next = entry;
+        list[next].prev = entry;
+    }
+
+    fn remove(list: &mut [Entry], entry: usize) {
+        let next = list[entry].next;
+        let prev = list[entry].prev;
+        list[prev].next = next;
+        list[next].prev = prev;
+    }
+
+    for _ in 0..rounds {
+        for entry in 0..list.len() {
+            remove(&mut list, entry);
+
+            let after = if list[entry].value < 0 {
+                let count: usize = list[entry].value.checked_neg().unwrap().try_into().unwrap();
+                prev(&list, list[entry].prev, count % (list.len() - 1))
+            } else {
+                let count: usize = list[entry].value.try_into().unwrap();
+                next(&list, list[entry].prev, count % (list.len() - 1))
+            };
+
+            insert_after(&mut list, entry, after);
+        }
+    }
+
+    let mut remaining = list.len();
+    let mut current = 0;
+    iter::from_fn(move || {
+        if remaining == 0 {
+            None
+        } else {
+            let value = list[current].value;
+            current = list[current].next;
+            remaining -= 1;
+            Some(value)
+        }
+    })
+}
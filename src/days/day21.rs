@@ -0,0 +1,719 @@
+//! Day 21: Monkey Math.
+
+use std::{collections::HashMap, fmt::Display, str::FromStr};
+
+use anyhow::{bail, Result};
+use lazy_static::lazy_static;
+use num_rational::Ratio;
+use num_traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, One, Zero};
+use petgraph::{
+    algo::toposort,
+    dot::{Config, Dot},
+    graph::{DiGraph, NodeIndex},
+    visit::DfsPostOrder,
+};
+use regex::Regex;
+
+use crate::{parse::map_lines, solution::Solution};
+
+pub struct Day21 {
+    monkeys: Vec<Monkey>,
+    root: String,
+    unknown: String,
+}
+
+impl Day21 {
+    pub fn monkeys(&self) -> &[Monkey] {
+        &self.monkeys
+    }
+
+    /// Replaces the monkey whose number is computed in part 1 and compared
+    /// in part 2, and the monkey whose number is unknown in part 2.
+    pub fn with_names(self, root: &str, unknown: &str) -> Self {
+        Self {
+            root: root.to_owned(),
+            unknown: unknown.to_owned(),
+            ..self
+        }
+    }
+}
+
+impl Solution for Day21 {
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Self {
+            monkeys: parse_input(input)?,
+            root: "root".to_owned(),
+            unknown: "humn".to_owned(),
+        })
+    }
+
+    fn part1(&self) -> Result<i64> {
+        whole_number(compute(&self.monkeys, &self.root)?)
+    }
+
+    fn part2(&self) -> Result<i64> {
+        whole_number(solve_for_unknown(&self.monkeys, &self.root, &self.unknown)?)
+    }
+
+    fn to_dot(&self) -> Option<Result<String>> {
+        Some(Ok(monkeys_to_dot(&self.monkeys)))
+    }
+}
+
+fn whole_number(value: Rational) -> Result<i64> {
+    if !value.is_integer() {
+        bail!("The answer is {}, which isn't a whole number", value);
+    }
+    Ok(value.to_integer().try_into()?)
+}
+
+/// An exact fraction, which all of the solver's arithmetic is done in.
+pub type Rational = Ratio<i128>;
+
+/// An expression of the form `a*x + b`, where `x` is the unknown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Linear {
+    coefficient: Rational,
+    constant: Rational,
+}
+
+impl Linear {
+    pub fn constant(value: Rational) -> Self {
+        Self {
+            coefficient: Rational::zero(),
+            constant: value,
+        }
+    }
+
+    pub fn unknown() -> Self {
+        Self {
+            coefficient: Rational::one(),
+            constant: Rational::zero(),
+        }
+    }
+
+    /// The `a` in `a*x + b`.
+    pub fn coefficient(&self) -> Rational {
+        self.coefficient
+    }
+
+    /// The `b` in `a*x + b`.
+    pub fn constant_term(&self) -> Rational {
+        self.constant
+    }
+
+    /// Applies the operation of the given monkey to two expressions. Fails when
+    /// the result wouldn't be linear, would divide by zero, or overflows.
+    fn apply(op: Operation, left: Self, right: Self, monkey: &str) -> Result<Self, EquationError> {
+        let overflow = || EquationError::Overflow(monkey.to_owned());
+
+        let result = match op {
+            Operation::Add => Self {
+                coefficient: left
+                    .coefficient
+                    .checked_add(&right.coefficient)
+                    .ok_or_else(overflow)?,
+                constant: left
+                    .constant
+                    .checked_add(&right.constant)
+                    .ok_or_else(overflow)?,
+            },
+            Operation::Sub => Self {
+                coefficient: left
+                    .coefficient
+                    .checked_sub(&right.coefficient)
+                    .ok_or_else(overflow)?,
+                constant: left
+                    .constant
+                    .checked_sub(&right.constant)
+                    .ok_or_else(overflow)?,
+            },
+            Operation::Mul => {
+                if !left.coefficient.is_zero() && !right.coefficient.is_zero() {
+                    return Err(EquationError::NotLinear(monkey.to_owned()));
+                }
+
+                // (a*x + b) * (c*x + d) = (a*d + b*c)*x + b*d, since a*c is zero
+                let cross_left = left
+                    .coefficient
+                    .checked_mul(&right.constant)
+                    .ok_or_else(overflow)?;
+                let cross_right = left
+                    .constant
+                    .checked_mul(&right.coefficient)
+                    .ok_or_else(overflow)?;
+                Self {
+                    coefficient: cross_left.checked_add(&cross_right).ok_or_else(overflow)?,
+                    constant: left
+                        .constant
+                        .checked_mul(&right.constant)
+                        .ok_or_else(overflow)?,
+                }
+            }
+            Operation::Div => {
+                if !right.coefficient.is_zero() {
+                    return Err(EquationError::NotLinear(monkey.to_owned()));
+                }
+                if right.constant.is_zero() {
+                    return Err(EquationError::DivisionByZero(monkey.to_owned()));
+                }
+
+                Self {
+                    coefficient: left
+                        .coefficient
+                        .checked_div(&right.constant)
+                        .ok_or_else(overflow)?,
+                    constant: left
+                        .constant
+                        .checked_div(&right.constant)
+                        .ok_or_else(overflow)?,
+                }
+            }
+        };
+
+        Ok(result)
+    }
+}
+
+impl Display for Linear {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}*x + {}", self.coefficient, self.constant)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EquationError {
+    /// Some monkey waits for a monkey that isn't in the list.
+    UndefinedMonkey(String),
+    /// More than one monkey has this name.
+    DuplicateMonkey(String),
+    /// The monkey ends up waiting for itself.
+    Cycle(String),
+    /// The monkey's job isn't linear in the unknown, such as when it
+    /// multiplies the unknown by itself, or divides by it.
+    NotLinear(String),
+    DivisionByZero(String),
+    /// The numbers in the monkey's job got too large.
+    Overflow(String),
+    /// The monkey doesn't wait for two others, so there's nothing to compare.
+    NotAnEquation(String),
+    NoSolution,
+    InfinitelyManySolutions,
+}
+
+impl Display for EquationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EquationError::UndefinedMonkey(name) => write!(f, "There is no monkey named {}", name),
+            EquationError::DuplicateMonkey(name) => {
+                write!(f, "There is more than one monkey named {}", name)
+            }
+            EquationError::Cycle(name) => write!(f, "Monkey {} ends up waiting for itself", name),
+            EquationError::NotLinear(name) => {
+                write!(f, "Monkey {} doesn't compute a linear expression", name)
+            }
+            EquationError::DivisionByZero(name) => write!(f, "Monkey {} divides by zero", name),
+            EquationError::Overflow(name) => write!(f, "Monkey {} overflows", name),
+            EquationError::NotAnEquation(name) => {
+                write!(f, "Monkey {} doesn't compare two other monkeys", name)
+            }
+            EquationError::NoSolution => write!(f, "The equation has no solution"),
+            EquationError::InfinitelyManySolutions => {
+                write!(f, "The equation has infinitely many solutions")
+            }
+        }
+    }
+}
+
+impl std::error::Error for EquationError {}
+
+/// Finds the number the `unknown` monkey has to yell for the two monkeys
+/// that `root` waits for to yell the same number. The unknown may appear
+/// anywhere, as long as both sides are linear in it.
+pub fn solve_for_unknown(
+    monkeys: &[Monkey],
+    root: &str,
+    unknown: &str,
+) -> Result<Rational, EquationError> {
+    let monkey = monkeys
+        .iter()
+        .find(|monkey| monkey.name == root)
+        .ok_or_else(|| EquationError::UndefinedMonkey(root.to_owned()))?;
+    let Job::Compute { left, right, .. } = &monkey.job else {
+        return Err(EquationError::NotAnEquation(root.to_owned()));
+    };
+
+    let left = fold(monkeys, left, unknown)?;
+    let right = fold(monkeys, right, unknown)?;
+
+    // a*x + b = c*x + d, so (a - c)*x = d - b
+    let overflow = || EquationError::Overflow(root.to_owned());
+    let coefficient = left
+        .coefficient
+        .checked_sub(&right.coefficient)
+        .ok_or_else(overflow)?;
+    let constant = right
+        .constant
+        .checked_sub(&left.constant)
+        .ok_or_else(overflow)?;
+
+    if coefficient.is_zero() {
+        if constant.is_zero() {
+            Err(EquationError::InfinitelyManySolutions)
+        } else {
+            Err(EquationError::NoSolution)
+        }
+    } else {
+        constant.checked_div(&coefficient).ok_or_else(overflow)
+    }
+}
+
+/// Computes the number the `root` monkey yells.
+pub fn compute(monkeys: &[Monkey], root: &str) -> Result<Rational, EquationError> {
+    let expression = evaluate(monkeys, root, None, |monkey, operands| {
+        compute_job(monkey, operands)
+    })?;
+    Ok(expression.constant)
+}
+
+/// Folds the expression computed by the `root` monkey into the form `a*x + b`,
+/// where `x` is the number yelled by the `unknown` monkey.
+pub fn fold(monkeys: &[Monkey], root: &str, unknown: &str) -> Result<Linear, EquationError> {
+    evaluate(monkeys, root, Some(unknown), |monkey, operands| {
+        if monkey.name == unknown {
+            Ok(Linear::unknown())
+        } else {
+            compute_job(monkey, operands)
+        }
+    })
+}
+
+/// Writes out the expression computed by the `root` monkey as an infix formula,
+/// with every operation in parentheses. The `unknown` monkey, if any, appears
+/// by name.
+///
+/// With `fold_constants`, every part of the formula that doesn't depend
+/// on the unknown is replaced by its value.
+pub fn format_expression(
+    monkeys: &[Monkey],
+    root: &str,
+    unknown: Option<&str>,
+    fold_constants: bool,
+) -> Result<String, EquationError> {
+    #[derive(Debug, Clone)]
+    struct Formula {
+        text: String,
+        /// The value of the formula, when constants are folded
+        /// and it doesn't depend on the unknown
+        value: Option<Rational>,
+    }
+
+    let formula = evaluate(monkeys, root, unknown, |monkey, operands| {
+        if Some(monkey.name.as_str()) == unknown {
+            return Ok(Formula {
+                text: monkey.name.clone(),
+                value: None,
+            });
+        }
+
+        let formula = match (&monkey.job, operands) {
+            (&Job::Const(value), _) => Formula {
+                text: value.to_string(),
+                value: fold_constants.then(|| Rational::from_integer(value.into())),
+            },
+            (Job::Compute { op, .. }, Some((left, right))) => {
+                if let (Some(left), Some(right)) = (left.value, right.value) {
+                    let value = Linear::apply(
+                        *op,
+                        Linear::constant(left),
+                        Linear::constant(right),
+                        &monkey.name,
+                    )?
+                    .constant;
+
+                    // Keep fractions in one piece
+                    let text = if value.is_integer() {
+                        value.to_string()
+                    } else {
+                        format!("({})", value)
+                    };
+
+                    Formula {
+                        text,
+                        value: Some(value),
+                    }
+                } else {
+                    Formula {
+                        text: format!("({} {} {})", left.text, op, right.text),
+                        value: None,
+                    }
+                }
+            }
+            (Job::Compute { .. }, None) => unreachable!("Operands are always given for a job"),
+        };
+
+        Ok(formula)
+    })?;
+
+    Ok(formula.text)
+}
+
+/// Computes the job of a monkey, given the expressions for the two monkeys
+/// it waits for, if it does.
+fn compute_job(
+    monkey: &Monkey,
+    operands: Option<(Linear, Linear)>,
+) -> Result<Linear, EquationError> {
+    match (&monkey.job, operands) {
+        (&Job::Const(value), _) => Ok(Linear::constant(Rational::from_integer(value.into()))),
+        (Job::Compute { op, .. }, Some((left, right))) => {
+            Linear::apply(*op, left, right, &monkey.name)
+        }
+        (Job::Compute { .. }, None) => unreachable!("Operands are always given for a job"),
+    }
+}
+
+/// Goes over every monkey the `root` monkey depends on, from the bottom up,
+/// and returns what `visit` computes for the root.
+///
+/// `visit` is given each monkey along with what it computed for the two monkeys
+/// it waits for, if it does. The `unknown` monkey is never given any, and the
+/// monkeys it waits for aren't visited on its account.
+fn evaluate<'a, T: Clone>(
+    monkeys: &'a [Monkey],
+    root: &str,
+    unknown: Option<&str>,
+    mut visit: impl FnMut(&'a Monkey, Option<(T, T)>) -> Result<T, EquationError>,
+) -> Result<T, EquationError> {
+    let mut graph = DiGraph::new();
+    let mut name_to_node_index = HashMap::new();
+
+    for monkey in monkeys {
+        let node_index = graph.add_node(monkey);
+        let inserted = name_to_node_index
+            .insert(monkey.name.as_str(), node_index)
+            .is_none();
+        if !inserted {
+            return Err(EquationError::DuplicateMonkey(monkey.name.clone()));
+        }
+    }
+
+    let node_index = |name: &str| {
+        name_to_node_index
+            .get(name)
+            .copied()
+            .ok_or_else(|| EquationError::UndefinedMonkey(name.to_owned()))
+    };
+
+    for monkey in monkeys {
+        // Whatever the unknown monkey waits for doesn't matter
+        if Some(monkey.name.as_str()) == unknown {
+            continue;
+        }
+
+        if let Job::Compute { left, right, .. } = &monkey.job {
+            let current = node_index(&monkey.name)?;
+            graph.add_edge(current, node_index(left)?, ());
+            graph.add_edge(current, node_index(right)?, ());
+        }
+    }
+
+    // In post-order, every monkey comes after the ones it waits for,
+    // unless they're waiting for it in turn
+    let mut computed: HashMap<NodeIndex, T> = HashMap::new();
+    let mut dfs = DfsPostOrder::new(&graph, node_index(root)?);
+    while let Some(node) = dfs.next(&graph) {
+        let monkey = graph[node];
+
+        let operands = match &monkey.job {
+            Job::Compute { left, right, .. } if Some(monkey.name.as_str()) != unknown => {
+                let operand = |name: &str| {
+                    computed
+                        .get(&node_index(name)?)
+                        .cloned()
+                        .ok_or_else(|| EquationError::Cycle(monkey.name.clone()))
+                };
+                Some((operand(left)?, operand(right)?))
+            }
+            _ => None,
+        };
+
+        let value = visit(monkey, operands)?;
+        computed.insert(node, value);
+    }
+
+    Ok(computed.remove(&node_index(root)?).unwrap())
+}
+
+/// Renders the monkeys in Graphviz DOT format, with an edge from every monkey
+/// to each of the two monkeys it waits for. Each monkey is labeled with its job
+/// and the number it ends up yelling.
+pub fn monkeys_to_dot(monkeys: &[Monkey]) -> String {
+    let mut graph = DiGraph::new();
+    let mut name_to_node_index = HashMap::new();
+
+    // Every monkey gets a node, even if its name is taken. Monkeys waiting
+    // for that name wait for the first monkey to have it.
+    let nodes = monkeys
+        .iter()
+        .map(|monkey| {
+            let node_index = graph.add_node((monkey, None::<Rational>));
+            name_to_node_index
+                .entry(monkey.name.as_str())
+                .or_insert(node_index);
+            node_index
+        })
+        .collect::<Vec<_>>();
+
+    // Monkeys waiting for a monkey that isn't defined have no edge to it
+    for (monkey, &current) in monkeys.iter().zip(&nodes) {
+        if let Job::Compute { left, right, .. } = &monkey.job {
+            for operand in [left, right] {
+                if let Some(&operand) = name_to_node_index.get(operand.as_str()) {
+                    graph.add_edge(current, operand, ());
+                }
+            }
+        }
+    }
+
+    // Compute the values bottom-up, exactly as the solver does. Monkeys waiting
+    // on an undefined or circular chain of monkeys, or whose job fails,
+    // are left without one.
+    if let Ok(order) = toposort(&graph, None) {
+        for node in order.into_iter().rev() {
+            let (monkey, _) = graph[node];
+            let expression = match &monkey.job {
+                Job::Const(_) => compute_job(monkey, None).ok(),
+                Job::Compute { left, right, .. } => {
+                    let value_of = |name: &str| {
+                        name_to_node_index
+                            .get(name)
+                            .and_then(|&operand| graph[operand].1)
+                            .map(Linear::constant)
+                    };
+                    match (value_of(left), value_of(right)) {
+                        (Some(left), Some(right)) => compute_job(monkey, Some((left, right))).ok(),
+                        _ => None,
+                    }
+                }
+            };
+            graph[node].1 = expression.map(|expression| expression.constant);
+        }
+    }
+
+    let node_attributes = |_, (_, (monkey, value)): (NodeIndex, &(&Monkey, Option<Rational>))| {
+        let job = match &monkey.job {
+            Job::Const(value) => value.to_string(),
+            Job::Compute { op, left, right } => format!("{} {} {}", left, op, right),
+        };
+        let label = match value {
+            Some(value) if !matches!(monkey.job, Job::Const(_)) => {
+                format!("{}: {}\n= {}", monkey.name, job, value)
+            }
+            _ => format!("{}: {}", monkey.name, job),
+        };
+        format!("label = {:?}", label)
+    };
+
+    let dot = Dot::with_attr_getters(
+        &graph,
+        &[Config::NodeNoLabel, Config::EdgeNoLabel],
+        &|_, _| String::new(),
+        &node_attributes,
+    );
+    format!("{:?}", dot)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Monkey {
+    name: String,
+    job: Job,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Job {
+    Const(i64),
+    Compute {
+        op: Operation,
+        left: String,
+        right: String,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+impl Display for Operation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let symbol = match self {
+            Self::Add => "+",
+            Self::Sub => "-",
+            Self::Mul => "*",
+            Self::Div => "/",
+        };
+        write!(f, "{}", symbol)
+    }
+}
+
+impl FromStr for Operation {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "+" => Ok(Self::Add),
+            "-" => Ok(Self::Sub),
+            "*" => Ok(Self::Mul),
+            "/" => Ok(Self::Div),
+            _ => bail!("Invalid operation {}", s),
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Monkey>> {
+    Ok(map_lines(input, |line| {
+        lazy_static! {
+            static ref REGEX: Regex =
+                Regex::new(r#"^(\w+): ((\w+) ([+\-*/]) (\w+)|\d+)$"#).unwrap();
+        }
+
+        if let Some(captures) = REGEX.captures(line) {
+            let name = captures.get(1).unwrap().as_str();
+
+            let job = if let Some(left) = captures.get(3) {
+                let left = left.as_str();
+                let right = captures.get(5).unwrap().as_str();
+                let op = captures.get(4).unwrap().as_str().parse()?;
+
+                Job::Compute {
+                    op,
+                    left: left.to_owned(),
+                    right: right.to_owned(),
+                }
+            } else {
+                let value = captures.get(2).unwrap().as_str().parse()?;
+
+                Job::Const(value)
+            };
+
+            Ok(Monkey {
+                name: name.to_owned(),
+                job,
+            })
+        } else {
+            bail!("Invalid descriptor {}", line);
+        }
+    })?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        compute, format_expression, monkeys_to_dot, parse_input, solve_for_unknown, EquationError,
+        Rational,
+    };
+
+    fn solve(input: &str) -> Result<Rational, EquationError> {
+        solve_for_unknown(&parse_input(input).unwrap(), "root", "humn")
+    }
+
+    #[test]
+    fn unknown_on_both_sides() {
+        let input =
+            "root: aaaa + bbbb\naaaa: humn * cccc\ncccc: 3\nbbbb: humn + dddd\ndddd: 4\nhumn: 0";
+        assert_eq!(solve(input), Ok(Rational::from_integer(2)));
+    }
+
+    #[test]
+    fn exact_division() {
+        // (x / 2) * 2 = 3, where working back with integer division
+        // would truncate 3 / 2 along the way
+        let input =
+            "root: aaaa + bbbb\naaaa: cccc * dddd\ncccc: humn / dddd\ndddd: 2\nbbbb: 3\nhumn: 0";
+        assert_eq!(solve(input), Ok(Rational::from_integer(3)));
+
+        let input = "root: aaaa + bbbb\naaaa: humn * cccc\ncccc: 2\nbbbb: 3\nhumn: 0";
+        assert_eq!(solve(input), Ok(Rational::new(3, 2)));
+    }
+
+    #[test]
+    fn degenerate_equations() {
+        let input = "root: humn + humn\nhumn: 0";
+        assert_eq!(solve(input), Err(EquationError::InfinitelyManySolutions));
+
+        let input = "root: aaaa + bbbb\naaaa: humn - humn\nbbbb: 1\nhumn: 0";
+        assert_eq!(solve(input), Err(EquationError::NoSolution));
+
+        let input = "root: aaaa + bbbb\naaaa: humn * humn\nbbbb: 1\nhumn: 0";
+        assert_eq!(
+            solve(input),
+            Err(EquationError::NotLinear("aaaa".to_owned()))
+        );
+    }
+
+    #[test]
+    fn duplicate_monkeys() {
+        let monkeys = parse_input(
+            "root: aaaa + humn
+aaaa: 1
+aaaa: 2
+humn: 3",
+        )
+        .unwrap();
+        let duplicate = Err(EquationError::DuplicateMonkey("aaaa".to_owned()));
+        assert_eq!(compute(&monkeys, "root"), duplicate);
+        assert_eq!(solve_for_unknown(&monkeys, "root", "humn"), duplicate);
+    }
+
+    #[test]
+    fn formulas() {
+        let monkeys = parse_input(
+            "root: aaaa + bbbb\naaaa: humn / cccc\ncccc: 2\nbbbb: cccc * dddd\ndddd: 3\nhumn: 5",
+        )
+        .unwrap();
+
+        let formula = format_expression(&monkeys, "root", Some("humn"), false);
+        assert_eq!(formula.unwrap(), "((humn / 2) + (2 * 3))");
+
+        let formula = format_expression(&monkeys, "root", Some("humn"), true);
+        assert_eq!(formula.unwrap(), "((humn / 2) + 6)");
+
+        let formula = format_expression(&monkeys, "root", None, true);
+        assert_eq!(formula.unwrap(), "(17/2)");
+
+        let formula = format_expression(&monkeys, "bbbb", Some("dddd"), true);
+        assert_eq!(formula.unwrap(), "(2 * dddd)");
+    }
+
+    #[test]
+    fn dot_with_undefined_and_duplicate_monkeys() {
+        let monkeys =
+            parse_input("root: aaaa + zzzz\naaaa: 1\naaaa: 2\nbbbb: aaaa * cccc\ncccc: 3").unwrap();
+        let dot = monkeys_to_dot(&monkeys);
+
+        // zzzz isn't defined, and both monkeys waiting for aaaa wait for the first one
+        assert_eq!(dot.matches(" -> ").count(), 3);
+        assert!(dot.contains("0 -> 1"));
+        assert!(dot.contains("3 -> 1"));
+        assert!(dot.contains("3 -> 4"));
+        assert!(dot.contains(r#"label = "root: aaaa + zzzz""#));
+        assert!(dot.contains(r#"label = "bbbb: aaaa * cccc\n= 3""#));
+    }
+
+    #[test]
+    fn dot_with_fractions() {
+        let monkeys =
+            parse_input("root: aaaa / bbbb\naaaa: 7\nbbbb: 2\ncccc: root * bbbb").unwrap();
+        let dot = monkeys_to_dot(&monkeys);
+
+        assert!(dot.contains(r#"label = "root: aaaa / bbbb\n= 7/2""#));
+        assert!(dot.contains(r#"label = "cccc: root * bbbb\n= 7""#));
+    }
+}
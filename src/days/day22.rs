@@ -0,0 +1,500 @@
+//! Day 22: Monkey Map.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt::Display,
+};
+
+use anyhow::{bail, Context, Result};
+use itertools::{iproduct, Either, Itertools};
+use nalgebra::{Point2, Vector2, Vector3};
+use nom::{
+    branch::alt,
+    character::complete::{char, line_ending},
+    combinator::{map, value},
+    multi::many1,
+    sequence::{pair, separated_pair},
+};
+
+use crate::{
+    grid::{self, Grid},
+    parse::{parse_all, ragged_char_grid, unsigned},
+    solution::Solution,
+};
+
+pub struct Day22 {
+    map: Map,
+    instructions: Vec<Instruction>,
+}
+
+impl Day22 {
+    pub fn map(&self) -> &Map {
+        &self.map
+    }
+
+    pub fn instructions(&self) -> &[Instruction] {
+        &self.instructions
+    }
+}
+
+impl Solution for Day22 {
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self> {
+        let (map, instructions) = parse_input(input)?;
+        Ok(Self { map, instructions })
+    }
+
+    fn part1(&self) -> Result<u32> {
+        Ok(self.map.simulate(self.instructions.iter().copied()))
+    }
+
+    fn part2(&self) -> Result<u32> {
+        let cube = Cube::fold(&self.map)?;
+        Ok(self
+            .map
+            .simulate_cube(&cube, self.instructions.iter().copied()))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Map {
+    tiles: Grid<Tile>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Tile {
+    #[default]
+    Void,
+    Open,
+    Wall,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Instruction {
+    Move(u8),
+    TurnCW,
+    TurnCCW,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Right = 0,
+    Down = 1,
+    Left = 2,
+    Up = 3,
+}
+
+impl Direction {
+    /// A unit step in this direction on the map.
+    pub fn offset(&self) -> Vector2<isize> {
+        match self {
+            Direction::Right => grid::E,
+            Direction::Down => grid::S,
+            Direction::Left => grid::W,
+            Direction::Up => grid::N,
+        }
+    }
+
+    pub fn turn(&self, instruction: Instruction) -> Self {
+        match instruction {
+            Instruction::Move(_) => *self,
+            Instruction::TurnCW => match self {
+                Direction::Right => Direction::Down,
+                Direction::Down => Direction::Left,
+                Direction::Left => Direction::Up,
+                Direction::Up => Direction::Right,
+            },
+            Instruction::TurnCCW => match self {
+                Direction::Right => Direction::Up,
+                Direction::Down => Direction::Right,
+                Direction::Left => Direction::Down,
+                Direction::Up => Direction::Left,
+            },
+        }
+    }
+}
+
+impl Map {
+    pub fn start(&self) -> (usize, usize) {
+        let column = self
+            .tiles
+            .row(0)
+            .indexed_iter()
+            .find_map(|(x, tile)| if let Tile::Open = tile { Some(x) } else { None })
+            .unwrap();
+        (0, column)
+    }
+
+    pub fn password(row: usize, column: usize, facing: Direction) -> u32 {
+        let row: u32 = row.try_into().unwrap();
+        let column: u32 = column.try_into().unwrap();
+        1000 * (row + 1) + 4 * (column + 1) + facing as u32
+    }
+
+    pub fn simulate(&self, instructions: impl IntoIterator<Item = Instruction>) -> u32 {
+        let (mut row, mut column) = self.start();
+        let mut facing = Direction::Right;
+
+        for instruction in instructions {
+            match instruction {
+                Instruction::Move(steps) => {
+                    let axis = match facing {
+                        Direction::Right | Direction::Left => self.tiles.row(row),
+                        Direction::Down | Direction::Up => self.tiles.column(column),
+                    };
+
+                    let sliced = match facing {
+                        Direction::Right => Either::Left(column..self.tiles.width()),
+                        Direction::Down => Either::Left(row..self.tiles.height()),
+                        Direction::Left => Either::Right((0..=column).rev()),
+                        Direction::Up => Either::Right((0..=row).rev()),
+                    };
+                    let full = match facing {
+                        Direction::Right => Either::Left(0..self.tiles.width()),
+                        Direction::Down => Either::Left(0..self.tiles.height()),
+                        Direction::Left => Either::Right((0..self.tiles.width()).rev()),
+                        Direction::Up => Either::Right((0..self.tiles.height()).rev()),
+                    };
+                    let indices = sliced.chain(full.cycle());
+                    let indices = indices.filter(|&index| !matches!(axis[index], Tile::Void));
+                    let indices = indices.take_while(|&index| !matches!(axis[index], Tile::Wall));
+
+                    let new_index = indices.take((steps + 1).into()).last().unwrap();
+
+                    match facing {
+                        Direction::Right | Direction::Left => column = new_index,
+                        Direction::Down | Direction::Up => row = new_index,
+                    }
+                }
+                Instruction::TurnCW | Instruction::TurnCCW => facing = facing.turn(instruction),
+            }
+        }
+
+        Self::password(row, column, facing)
+    }
+
+    pub fn simulate_cube(
+        &self,
+        cube: &Cube,
+        instructions: impl IntoIterator<Item = Instruction>,
+    ) -> u32 {
+        let (mut row, mut column) = self.start();
+        let mut facing = Direction::Right;
+
+        for instruction in instructions {
+            match instruction {
+                Instruction::Move(steps) => {
+                    for _ in 0..steps {
+                        let (new_row, new_column, new_facing) = self
+                            .step_on_map(row, column, facing)
+                            .unwrap_or_else(|| cube.step_over_edge(row, column, facing));
+
+                        if let Tile::Wall = self.tiles[Point2::new(new_column, new_row)] {
+                            break;
+                        }
+
+                        (row, column, facing) = (new_row, new_column, new_facing);
+                    }
+                }
+                Instruction::TurnCW | Instruction::TurnCCW => facing = facing.turn(instruction),
+            }
+        }
+
+        Self::password(row, column, facing)
+    }
+
+    /// Moves one tile in the given direction, without any wrapping.
+    /// Returns `None` if the move would leave the map.
+    fn step_on_map(
+        &self,
+        row: usize,
+        column: usize,
+        facing: Direction,
+    ) -> Option<(usize, usize, Direction)> {
+        let destination = self.tiles.step(Point2::new(column, row), facing.offset())?;
+
+        match self.tiles[destination] {
+            Tile::Void => None,
+            _ => Some((destination.y, destination.x, facing)),
+        }
+    }
+}
+
+/// The map, folded into a cube.
+///
+/// Each face of the cube is identified by its position in the net, measured
+/// in units of the face size. A face's orientation in space is given by three
+/// unit vectors: the outward normal, and the directions that point right and
+/// down along the face when looking at the net.
+#[derive(Debug, Clone)]
+pub struct Cube {
+    size: usize,
+    faces: HashMap<(usize, usize), Face>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Face {
+    normal: Vector3<i32>,
+    right: Vector3<i32>,
+    down: Vector3<i32>,
+}
+
+impl Cube {
+    pub fn fold(map: &Map) -> Result<Self> {
+        let area = map
+            .tiles
+            .iter()
+            .filter(|(_, tile)| !matches!(tile, Tile::Void))
+            .count();
+        if area % 6 != 0 {
+            bail!("Map area {} is not divisible into six faces", area);
+        }
+
+        let size = (area / 6).isqrt();
+        if size == 0 {
+            bail!("Map is empty");
+        }
+        if size * size != area / 6 {
+            bail!("Map area {} doesn't form six square faces", area);
+        }
+
+        let (height, width) = (map.tiles.height(), map.tiles.width());
+        if height % size != 0 || width % size != 0 {
+            bail!("Map dimensions aren't multiples of the face size {}", size);
+        }
+
+        let is_face = |(face_row, face_column): (usize, usize)| {
+            !matches!(
+                map.tiles[Point2::new(face_column * size, face_row * size)],
+                Tile::Void
+            )
+        };
+
+        let net_positions = iproduct!(0..height / size, 0..width / size)
+            .filter(|&position| is_face(position))
+            .collect_vec();
+        for &(face_row, face_column) in &net_positions {
+            let face_tiles = map.tiles.view().slice_move(ndarray::s![
+                face_row * size..(face_row + 1) * size,
+                face_column * size..(face_column + 1) * size
+            ]);
+            if face_tiles.iter().any(|tile| matches!(tile, Tile::Void)) {
+                bail!("Face at ({}, {}) is not square", face_row, face_column);
+            }
+        }
+
+        // Walk the net, folding each newly discovered face over the edge
+        // it shares with an already placed neighbour
+        let first = *net_positions.first().context("Map is empty")?;
+        let mut faces = HashMap::new();
+        faces.insert(
+            first,
+            Face {
+                normal: Vector3::new(0, 0, -1),
+                right: Vector3::new(1, 0, 0),
+                down: Vector3::new(0, 1, 0),
+            },
+        );
+
+        let mut to_visit = VecDeque::from([first]);
+        while let Some(current) = to_visit.pop_front() {
+            let face = faces[&current];
+            let (face_row, face_column) = current;
+
+            let neighbours = [
+                (
+                    Some(face_row),
+                    face_column.checked_add(1),
+                    Face {
+                        normal: face.right,
+                        right: -face.normal,
+                        down: face.down,
+                    },
+                ),
+                (
+                    face_row.checked_add(1),
+                    Some(face_column),
+                    Face {
+                        normal: face.down,
+                        right: face.right,
+                        down: -face.normal,
+                    },
+                ),
+                (
+                    Some(face_row),
+                    face_column.checked_sub(1),
+                    Face {
+                        normal: -face.right,
+                        right: face.normal,
+                        down: face.down,
+                    },
+                ),
+                (
+                    face_row.checked_sub(1),
+                    Some(face_column),
+                    Face {
+                        normal: -face.down,
+                        right: face.right,
+                        down: face.normal,
+                    },
+                ),
+            ];
+
+            for (neighbour_row, neighbour_column, neighbour_face) in neighbours {
+                let (Some(neighbour_row), Some(neighbour_column)) =
+                    (neighbour_row, neighbour_column)
+                else {
+                    continue;
+                };
+                let neighbour = (neighbour_row, neighbour_column);
+                if !net_positions.contains(&neighbour) || faces.contains_key(&neighbour) {
+                    continue;
+                }
+                faces.insert(neighbour, neighbour_face);
+                to_visit.push_back(neighbour);
+            }
+        }
+
+        if faces.len() != 6 {
+            bail!("Expected the net to be connected");
+        }
+        if !faces.values().map(|face| face.normal).all_unique() {
+            bail!("Net doesn't fold into a cube");
+        }
+
+        Ok(Self { size, faces })
+    }
+
+    /// Moves one tile in the given direction, off the edge of the current face
+    /// and onto the adjacent face of the cube.
+    fn step_over_edge(
+        &self,
+        row: usize,
+        column: usize,
+        facing: Direction,
+    ) -> (usize, usize, Direction) {
+        let size: i32 = self.size.try_into().unwrap();
+
+        let position = (row / self.size, column / self.size);
+        let face = self.faces[&position];
+
+        let local_row: i32 = (row % self.size).try_into().unwrap();
+        let local_column: i32 = (column % self.size).try_into().unwrap();
+
+        // Coordinates of the tile's center on the surface of the cube,
+        // scaled by 2 so that everything stays integral. The cube spans
+        // [-size, size] along each axis.
+        let point = face.normal * size
+            + face.right * (2 * local_column + 1 - size)
+            + face.down * (2 * local_row + 1 - size);
+
+        let heading = face.direction_vector(facing);
+
+        // Going over the edge, we end up on the face whose normal is
+        // our current heading, moving away from the face we left
+        let (&new_position, new_face) = self
+            .faces
+            .iter()
+            .find(|(_, candidate)| candidate.normal == heading)
+            .unwrap();
+        let new_point = point + heading - face.normal;
+        let new_facing = new_face.direction(-face.normal);
+
+        let new_local_column: usize = ((new_point.dot(&new_face.right) + size - 1) / 2)
+            .try_into()
+            .unwrap();
+        let new_local_row: usize = ((new_point.dot(&new_face.down) + size - 1) / 2)
+            .try_into()
+            .unwrap();
+
+        (
+            new_position.0 * self.size + new_local_row,
+            new_position.1 * self.size + new_local_column,
+            new_facing,
+        )
+    }
+}
+
+impl Face {
+    pub fn direction_vector(&self, direction: Direction) -> Vector3<i32> {
+        match direction {
+            Direction::Right => self.right,
+            Direction::Down => self.down,
+            Direction::Left => -self.right,
+            Direction::Up => -self.down,
+        }
+    }
+
+    pub fn direction(&self, vector: Vector3<i32>) -> Direction {
+        [
+            Direction::Right,
+            Direction::Down,
+            Direction::Left,
+            Direction::Up,
+        ]
+        .into_iter()
+        .find(|&direction| self.direction_vector(direction) == vector)
+        .expect("Vector doesn't lie in the plane of the face")
+    }
+}
+
+impl TryFrom<char> for Tile {
+    type Error = anyhow::Error;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            ' ' => Ok(Self::Void),
+            '.' => Ok(Self::Open),
+            '#' => Ok(Self::Wall),
+            _ => bail!("Unknown tile type {}", value),
+        }
+    }
+}
+
+impl Display for Map {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.tiles)
+    }
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let c = match self {
+            Tile::Void => ' ',
+            Tile::Open => '.',
+            Tile::Wall => '#',
+        };
+        write!(f, "{}", c)
+    }
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Move(steps) => write!(f, "{}", steps),
+            Self::TurnCW => write!(f, "R"),
+            Self::TurnCCW => write!(f, "L"),
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Result<(Map, Vec<Instruction>)> {
+    let instruction = alt((
+        map(unsigned, Instruction::Move),
+        value(Instruction::TurnCW, char('R')),
+        value(Instruction::TurnCCW, char('L')),
+    ));
+
+    let (tiles, instructions) = parse_all(
+        input,
+        separated_pair(
+            map(ragged_char_grid(|c| Tile::try_from(c).ok()), Grid::new),
+            pair(line_ending, line_ending),
+            many1(instruction),
+        ),
+    )?;
+
+    Ok((Map { tiles }, instructions))
+}
@@ -0,0 +1,139 @@
+//! Day 23: Unstable Diffusion.
+
+use std::collections::{HashMap, HashSet};
+
+use anyhow::Result;
+use itertools::{Itertools, MinMaxResult};
+use nalgebra::{Point2, Vector2};
+
+use crate::{
+    grid::{Grid, ALL_DIRECTIONS, E, N, NE, NW, S, SE, SW, W},
+    solution::Solution,
+};
+
+pub struct Day23 {
+    positions: HashSet<Point2<isize>>,
+}
+
+impl Solution for Day23 {
+    type Part1 = u32;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Self {
+            positions: parse_input(input)?,
+        })
+    }
+
+    fn part1(&self) -> Result<u32> {
+        Ok(compute_empty_tiles(&mut self.positions.clone(), 10))
+    }
+
+    fn part2(&self) -> Result<usize> {
+        let rounds_until_steady = simulate_until_steady_state(&mut self.positions.clone());
+        Ok(rounds_until_steady + 1)
+    }
+}
+
+pub fn compute_empty_tiles(positions: &mut HashSet<Point2<isize>>, rounds: usize) -> u32 {
+    if positions.is_empty() {
+        return 0;
+    }
+
+    // Simulate movement
+    for round in 0..rounds {
+        simulate1(positions, round);
+    }
+
+    // Compute bounding rectangle
+    let (min_x, max_x) = match positions.iter().minmax_by_key(|point| point.x) {
+        MinMaxResult::NoElements => unreachable!(),
+        MinMaxResult::OneElement(minmax) => (minmax.x, minmax.x),
+        MinMaxResult::MinMax(min, max) => (min.x, max.x),
+    };
+    let (min_y, max_y) = match positions.iter().minmax_by_key(|point| point.y) {
+        MinMaxResult::NoElements => unreachable!(),
+        MinMaxResult::OneElement(minmax) => (minmax.y, minmax.y),
+        MinMaxResult::MinMax(min, max) => (min.y, max.y),
+    };
+    let width = min_x.abs_diff(max_x) + 1;
+    let height = min_y.abs_diff(max_y) + 1;
+
+    let total_tiles = width * height;
+    (total_tiles - positions.len()).try_into().unwrap()
+}
+
+pub fn simulate_until_steady_state(positions: &mut HashSet<Point2<isize>>) -> usize {
+    for round in 0.. {
+        let previous = positions.clone();
+        simulate1(positions, round);
+        if &previous == positions {
+            return round;
+        }
+    }
+    unreachable!()
+}
+
+fn simulate1(positions: &mut HashSet<Point2<isize>>, round: usize) {
+    const PROPOSAL_ORDER: [([Vector2<isize>; 3], Vector2<isize>); 4] = [
+        ([N, NE, NW], N),
+        ([S, SE, SW], S),
+        ([W, NW, SW], W),
+        ([E, NE, SE], E),
+    ];
+
+    // Maps a point to move to, to the elves that want to move there
+    let mut proposals: HashMap<Point2<isize>, Vec<Point2<isize>>> = HashMap::new();
+
+    // First half of the round
+    for position in &*positions {
+        // If no other Elves are in one of those eight positions,
+        // the Elf does not do anything during this round.
+        if !ALL_DIRECTIONS
+            .into_iter()
+            .any(|direction| positions.contains(&(position + direction)))
+        {
+            continue;
+        }
+
+        // Otherwise, the Elf looks in each of four directions in the following
+        // order and proposes moving one step in the first valid direction.
+        for i in round..round + PROPOSAL_ORDER.len() {
+            let i = i % PROPOSAL_ORDER.len();
+            let (checks, direction) = PROPOSAL_ORDER[i];
+            if !checks
+                .into_iter()
+                .any(|check| positions.contains(&(position + check)))
+            {
+                proposals
+                    .entry(position + direction)
+                    .or_default()
+                    .push(*position);
+                break;
+            }
+        }
+    }
+
+    for (proposal, contenders) in proposals {
+        assert!(!contenders.is_empty());
+        if contenders.len() > 1 {
+            continue;
+        }
+        positions.remove(&contenders[0]);
+        positions.insert(proposal);
+    }
+}
+
+fn parse_input(input: &str) -> Result<HashSet<Point2<isize>>> {
+    let elves = Grid::parse(input, |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })?;
+
+    elves
+        .iter()
+        .filter(|(_, &elf)| elf)
+        .map(|(point, _)| Ok(Point2::new(point.x.try_into()?, point.y.try_into()?)))
+        .collect()
+}
@@ -0,0 +1,324 @@
+//! Day 24: Blizzard Basin.
+
+use std::fmt::Display;
+
+use anyhow::{bail, Context, Result};
+use itertools::Itertools;
+use nalgebra::{Point2, Vector2};
+use num_integer::Integer;
+
+use crate::{
+    grid::{self, Grid},
+    search::astar,
+    solution::Solution,
+    util::manhattan_distance,
+};
+
+pub struct Day24 {
+    map: Map,
+}
+
+impl Day24 {
+    pub fn map(&self) -> &Map {
+        &self.map
+    }
+}
+
+impl Solution for Day24 {
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Self {
+            map: parse_input(input)?,
+        })
+    }
+
+    fn part1(&self) -> Result<u32> {
+        let route = self
+            .map
+            .find_shortest_path(&[self.map.entrance(), self.map.exit()], 0)
+            .context("Didn't find a way through the valley")?;
+        Ok(route.len().try_into()?)
+    }
+
+    fn part2(&self) -> Result<u32> {
+        let (entrance, exit) = (self.map.entrance(), self.map.exit());
+        let route = self
+            .map
+            .find_shortest_path(&[entrance, exit, entrance, exit], 0)
+            .context("Didn't find a way back and forth through the valley")?;
+        Ok(route.len().try_into()?)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Map {
+    valley: Grid<Option<Direction>>,
+
+    /// The basin as of each minute of one period, walls included,
+    /// with `true` marking the tiles that are blocked by a wall or a blizzard.
+    /// Blizzards wrap around, so they're back where they started after
+    /// `lcm(width, height)` minutes.
+    frames: Vec<Grid<bool>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub const ALL: [Self; 4] = [Self::Up, Self::Down, Self::Left, Self::Right];
+
+    pub fn offset(&self) -> Vector2<isize> {
+        match self {
+            Self::Up => grid::N,
+            Self::Down => grid::S,
+            Self::Left => grid::W,
+            Self::Right => grid::E,
+        }
+    }
+}
+
+/// What the expedition does in a single minute.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Move {
+    Wait,
+    Go(Direction),
+}
+
+impl Move {
+    pub const ALL: [Self; 5] = [
+        Self::Wait,
+        Self::Go(Direction::Up),
+        Self::Go(Direction::Down),
+        Self::Go(Direction::Left),
+        Self::Go(Direction::Right),
+    ];
+
+    pub fn offset(&self) -> Vector2<isize> {
+        match self {
+            Self::Wait => Vector2::zeros(),
+            Self::Go(direction) => direction.offset(),
+        }
+    }
+}
+
+impl Display for Move {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Wait => "wait",
+            Self::Go(Direction::Up) => "up",
+            Self::Go(Direction::Down) => "down",
+            Self::Go(Direction::Left) => "left",
+            Self::Go(Direction::Right) => "right",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl Map {
+    fn new(valley: Grid<Option<Direction>>) -> Self {
+        let (width, height) = (valley.width(), valley.height());
+        let period = width.lcm(&height);
+
+        let mut map = Self {
+            valley,
+            frames: Vec::with_capacity(period),
+        };
+
+        for time in 0..period {
+            let frame = Grid::from_fn(width + 2, height + 2, |point| {
+                if point == map.entrance() || point == map.exit() {
+                    false
+                } else if point.x == 0 || point.y == 0 || point.x > width || point.y > height {
+                    true
+                } else {
+                    map.has_blizzard(point - Vector2::new(1, 1), time)
+                }
+            });
+            map.frames.push(frame);
+        }
+
+        map
+    }
+
+    /// The opening in the top wall. Like all points on the map, this one
+    /// is counted from the top left corner of the walls.
+    pub fn entrance(&self) -> Point2<usize> {
+        Point2::new(1, 0)
+    }
+
+    /// The opening in the bottom wall.
+    pub fn exit(&self) -> Point2<usize> {
+        Point2::new(self.valley.width(), self.valley.height() + 1)
+    }
+
+    /// Finds the quickest route that starts at the first of the waypoints
+    /// at the given time, and then visits all the others in order.
+    pub fn find_shortest_path(
+        &self,
+        waypoints: &[Point2<usize>],
+        start_time: u32,
+    ) -> Option<Vec<Move>> {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        struct Node {
+            position: Point2<usize>,
+            /// Minutes since the start of the current blizzard period
+            phase: usize,
+            /// How many of the waypoints have been visited so far
+            visited: usize,
+        }
+
+        let (&first, _) = waypoints.split_first()?;
+
+        // How far it is from each waypoint to the last one, going through the rest
+        let mut remaining_distances = vec![0; waypoints.len()];
+        for index in (0..waypoints.len() - 1).rev() {
+            remaining_distances[index] = remaining_distances[index + 1]
+                + manhattan_distance(&waypoints[index], &waypoints[index + 1]);
+        }
+
+        let heuristic = |node: &Node| -> u32 {
+            let distance = match waypoints.get(node.visited) {
+                Some(next) => {
+                    manhattan_distance(&node.position, next) + remaining_distances[node.visited]
+                }
+                None => 0,
+            };
+            distance.try_into().unwrap()
+        };
+
+        // Consecutive waypoints in the same place are all reached at once
+        let reached = |position: Point2<usize>, mut visited: usize| {
+            while waypoints.get(visited) == Some(&position) {
+                visited += 1;
+            }
+            visited
+        };
+
+        let period = self.frames.len();
+        let neighbours = |&node: &Node| {
+            let phase = (node.phase + 1) % period;
+            let frame = &self.frames[phase];
+            Move::ALL
+                .into_iter()
+                .filter_map(move |step| frame.step(node.position, step.offset()))
+                .filter(move |&position| !frame[position])
+                .map(move |position| {
+                    let node = Node {
+                        position,
+                        phase,
+                        visited: reached(position, node.visited),
+                    };
+                    (node, 1)
+                })
+        };
+
+        let start = Node {
+            position: first,
+            phase: start_time as usize % period,
+            visited: reached(first, 1),
+        };
+
+        let path = astar(start, neighbours, heuristic, |node| {
+            node.visited == waypoints.len()
+        })?;
+
+        let moves = path
+            .nodes()
+            .iter()
+            .tuple_windows()
+            .map(|(from, to)| {
+                *Move::ALL
+                    .iter()
+                    .find(|step| {
+                        self.frames[0].step(from.position, step.offset()) == Some(to.position)
+                    })
+                    .unwrap()
+            })
+            .collect();
+
+        Some(moves)
+    }
+
+    /// Checks whether any blizzard is at the given point of the valley
+    /// (not counting the walls) at the given time.
+    fn has_blizzard(&self, point: Point2<usize>, time: usize) -> bool {
+        // Blizzards move in straight lines and wrap around, so only a blizzard
+        // that started out in the same row or column could have reached the point
+        let width = self.valley.width() as isize;
+        let height = self.valley.height() as isize;
+        let time = time as isize;
+
+        Direction::ALL.into_iter().any(|direction| {
+            let offset = direction.offset() * time;
+            let origin = Point2::new(
+                (point.x as isize - offset.x).rem_euclid(width) as usize,
+                (point.y as isize - offset.y).rem_euclid(height) as usize,
+            );
+            self.valley[origin] == Some(direction)
+        })
+    }
+}
+
+impl TryFrom<char> for Direction {
+    type Error = anyhow::Error;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '^' => Ok(Self::Up),
+            'v' => Ok(Self::Down),
+            '<' => Ok(Self::Left),
+            '>' => Ok(Self::Right),
+            _ => bail!("Invalid direction {}", c),
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Result<Map> {
+    let tiles = Grid::parse(input, |c| match c {
+        '#' | '.' | '^' | 'v' | '<' | '>' => Some(c),
+        _ => None,
+    })?;
+
+    let (width, height) = (tiles.width(), tiles.height());
+    if height < 3 || width < 3 {
+        bail!("Map is too small");
+    }
+
+    // The valley is surrounded by walls, except for the entrance
+    // at the top left and the exit at the bottom right
+    for (point, &tile) in tiles.iter() {
+        let (x, y) = (point.x, point.y);
+        let is_border = y == 0 || y == height - 1 || x == 0 || x == width - 1;
+        let is_opening = (y, x) == (0, 1) || (y, x) == (height - 1, width - 2);
+        let expected_wall = is_border && !is_opening;
+        if (tile == '#') != expected_wall {
+            bail!(
+                "Unexpected tile '{}' at row {}, column {}",
+                tile,
+                y + 1,
+                x + 1
+            );
+        }
+        if is_opening && tile != '.' {
+            bail!("Expected opening at row {}, column {}", y + 1, x + 1);
+        }
+    }
+
+    // The actual map we're interested in doesn't contain the walls
+    let mut valley = Grid::from_fn(width - 2, height - 2, |_| None);
+    for (point, blizzard) in valley.iter_mut() {
+        let tile = tiles[point + Vector2::new(1, 1)];
+        if tile != '.' {
+            *blizzard = Some(tile.try_into()?);
+        }
+    }
+
+    Ok(Map::new(valley))
+}
@@ -0,0 +1,84 @@
+//! Day 25: Full of Hot Air.
+
+use std::convert::Infallible;
+
+use anyhow::{bail, Context, Result};
+
+use crate::{
+    parse::map_lines,
+    radix::{Alphabet, BalancedInt},
+    solution::Solution,
+};
+
+pub struct Day25 {
+    numbers: Vec<SnafuInt>,
+}
+
+impl Day25 {
+    pub fn numbers(&self) -> &[SnafuInt] {
+        &self.numbers
+    }
+}
+
+impl Solution for Day25 {
+    type Part1 = SnafuInt;
+    type Part2 = Infallible;
+
+    const PARTS: u8 = 1;
+
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Self {
+            numbers: map_lines(input, |line| Ok(line.parse()?))?,
+        })
+    }
+
+    fn part1(&self) -> Result<SnafuInt> {
+        self.numbers
+            .iter()
+            .cloned()
+            .reduce(|acc, number| acc + number)
+            .context("Expected at least one number")
+    }
+
+    fn part2(&self) -> Result<Infallible> {
+        bail!("Day 25 has only one part")
+    }
+}
+
+/// A number written in balanced base 5, with the digits `=`, `-`, `0`, `1` and `2`
+/// standing for -2 through 2. Every number, negative ones included, has a single
+/// representation, so there's no separate sign.
+pub type SnafuInt = BalancedInt<5, SnafuDigits>;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SnafuDigits;
+
+impl Alphabet for SnafuDigits {
+    const DIGITS: &'static str = "=-012";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SnafuInt;
+
+    fn snafu(value: i64) -> SnafuInt {
+        value.into()
+    }
+
+    #[test]
+    fn conversions() {
+        for (value, text) in [
+            (0, "0"),
+            (3, "1="),
+            (-3, "-2"),
+            (2022, "1=11-2"),
+            (-8, "=2"),
+        ] {
+            assert_eq!(snafu(value).to_string(), text);
+            assert_eq!(text.parse::<SnafuInt>().unwrap(), snafu(value));
+            assert_eq!(i64::try_from(snafu(value)).unwrap(), value);
+        }
+
+        assert_eq!("002".parse::<SnafuInt>().unwrap().to_string(), "2");
+    }
+}
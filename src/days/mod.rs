@@ -0,0 +1,66 @@
+//! Solutions for each day of the puzzle, usable as a library.
+
+use anyhow::{bail, Result};
+
+use crate::solution::{AnySolution, Solution};
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+/// The days for which a solution exists.
+pub const DAYS: std::ops::RangeInclusive<u8> = 1..=25;
+
+/// Parses the input of the given day into its solution.
+pub fn parse(day: u8, input: &str) -> Result<Box<dyn AnySolution>> {
+    Ok(match day {
+        1 => Box::new(day01::Day01::parse(input)?),
+        2 => Box::new(day02::Day02::parse(input)?),
+        3 => Box::new(day03::Day03::parse(input)?),
+        4 => Box::new(day04::Day04::parse(input)?),
+        5 => Box::new(day05::Day05::parse(input)?),
+        6 => Box::new(day06::Day06::parse(input)?),
+        7 => Box::new(day07::Day07::parse(input)?),
+        8 => Box::new(day08::Day08::parse(input)?),
+        9 => Box::new(day09::Day09::parse(input)?),
+        10 => Box::new(day10::Day10::parse(input)?),
+        11 => Box::new(day11::Day11::parse(input)?),
+        12 => Box::new(day12::Day12::parse(input)?),
+        13 => Box::new(day13::Day13::parse(input)?),
+        14 => Box::new(day14::Day14::parse(input)?),
+        15 => Box::new(day15::Day15::parse(input)?),
+        16 => Box::new(day16::Day16::parse(input)?),
+        17 => Box::new(day17::Day17::parse(input)?),
+        18 => Box::new(day18::Day18::parse(input)?),
+        19 => Box::new(day19::Day19::parse(input)?),
+        20 => Box::new(day20::Day20::parse(input)?),
+        21 => Box::new(day21::Day21::parse(input)?),
+        22 => Box::new(day22::Day22::parse(input)?),
+        23 => Box::new(day23::Day23::parse(input)?),
+        24 => Box::new(day24::Day24::parse(input)?),
+        25 => Box::new(day25::Day25::parse(input)?),
+        _ => bail!("No solution for day {}", day),
+    })
+}
@@ -1,9 +1,19 @@
 use std::{
-    fmt::Debug,
+    fmt::{Debug, Display},
     num::NonZeroU32,
-    ops::{Add, AddAssign, Mul, MulAssign},
+    ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign},
 };
 
+use num_integer::Integer;
+
+mod extension;
+mod matrix;
+mod polynomial;
+
+pub use extension::{ExtensionField, ExtensionInt, GF256Int};
+pub use matrix::Matrix;
+pub use polynomial::Polynomial;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GF {
     order: Option<NonZeroU32>,
@@ -15,6 +25,47 @@ pub struct GFInt {
     value: u32,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GFError {
+    /// The order isn't prime, so not every nonzero value has an inverse.
+    NotAField(GF),
+    /// The value has no multiplicative inverse.
+    NotInvertible(GFInt),
+    /// Two moduli of a residue system share a factor.
+    NotCoprime(NonZeroU32, NonZeroU32),
+    /// The product of the moduli of a residue system doesn't fit in a `u128`.
+    Overflow,
+    /// The polynomial defining an extension field has factors.
+    NotIrreducible,
+    /// Zero has no inverse in any field.
+    DivisionByZero,
+    /// The matrix has no inverse.
+    Singular,
+    /// The system of linear equations is inconsistent.
+    NoSolution,
+}
+
+impl Display for GFError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GFError::NotAField(field) => {
+                write!(f, "The integers modulo {} don't form a field", field)
+            }
+            GFError::NotInvertible(value) => {
+                write!(f, "{} has no inverse modulo {}", value.value, value.field)
+            }
+            GFError::NotCoprime(a, b) => write!(f, "The moduli {} and {} aren't coprime", a, b),
+            GFError::Overflow => write!(f, "The product of the moduli is too large"),
+            GFError::NotIrreducible => write!(f, "The polynomial isn't irreducible"),
+            GFError::DivisionByZero => write!(f, "Division by zero"),
+            GFError::Singular => write!(f, "The matrix is singular"),
+            GFError::NoSolution => write!(f, "The system of equations has no solution"),
+        }
+    }
+}
+
+impl std::error::Error for GFError {}
+
 impl GF {
     pub fn new(order: Option<NonZeroU32>) -> Self {
         Self { order }
@@ -24,6 +75,12 @@ pub fn order(&self) -> Option<NonZeroU32> {
         self.order
     }
 
+    /// Whether the order is prime, which is what makes the integers modulo it a field.
+    /// Without an order, the arithmetic wraps around at 2^32, which isn't prime.
+    pub fn is_field(&self) -> bool {
+        self.order.is_some_and(|order| is_prime(order.get()))
+    }
+
     pub fn create_value(&self, value: u32) -> GFInt {
         GFInt {
             field: *self,
@@ -51,6 +108,33 @@ pub fn square(&self) -> Self {
     pub fn square_assign(&mut self) {
         *self = self.square();
     }
+
+    /// Raises to the given power by repeated squaring.
+    pub fn pow(&self, exponent: u64) -> Self {
+        power(*self, self.field.create_value(1), exponent)
+    }
+
+    /// The multiplicative inverse, found with the extended Euclidean algorithm.
+    ///
+    /// Fails if the field's order isn't prime, or if the value is zero.
+    pub fn inv(&self) -> Result<Self, GFError> {
+        if !self.field.is_field() {
+            return Err(GFError::NotAField(self.field));
+        }
+        let order = self.field.order.unwrap().get();
+
+        let inverse = inverse_mod(self.value, order).ok_or(GFError::NotInvertible(*self))?;
+        Ok(self.field.create_value(inverse))
+    }
+}
+
+impl Display for GF {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.order {
+            Some(order) => write!(f, "{}", order),
+            None => write!(f, "2^32"),
+        }
+    }
 }
 
 impl Add for GFInt {
@@ -62,7 +146,7 @@ fn add(self, rhs: Self) -> Self::Output {
         Self {
             field: self.field,
             value: match self.field.order {
-                Some(order) => self.value.checked_add(rhs.value).unwrap() % order,
+                Some(order) => reduce(u64::from(self.value) + u64::from(rhs.value), order),
                 None => self.value.wrapping_add(rhs.value),
             },
         }
@@ -75,6 +159,35 @@ fn add_assign(&mut self, rhs: Self) {
     }
 }
 
+impl Sub for GFInt {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + (-rhs)
+    }
+}
+
+impl SubAssign for GFInt {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for GFInt {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            field: self.field,
+            value: match self.field.order {
+                Some(order) if self.value != 0 => order.get() - self.value,
+                Some(_) => 0,
+                None => self.value.wrapping_neg(),
+            },
+        }
+    }
+}
+
 impl Mul for GFInt {
     type Output = Self;
 
@@ -84,7 +197,7 @@ fn mul(self, rhs: Self) -> Self::Output {
         Self {
             field: self.field,
             value: match self.field.order {
-                Some(order) => self.value.checked_mul(rhs.value).unwrap() % order,
+                Some(order) => reduce(u64::from(self.value) * u64::from(rhs.value), order),
                 None => self.value.wrapping_mul(rhs.value),
             },
         }
@@ -97,11 +210,469 @@ fn mul_assign(&mut self, rhs: Self) {
     }
 }
 
+/// Multiplies by the inverse of the divisor, so fails whenever [`GFInt::inv`] does.
+impl Div for GFInt {
+    type Output = Result<Self, GFError>;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        assert_eq!(self.field, rhs.field);
+
+        Ok(self * rhs.inv()?)
+    }
+}
+
+/// Integers represented by their residues modulo several pairwise coprime moduli.
+/// By the Chinese remainder theorem, the residues determine the integer
+/// modulo the product of the moduli.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResidueSystem {
+    fields: Vec<GF>,
+}
+
+/// An integer in a [`ResidueSystem`], with one residue per modulus.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResidueInt {
+    residues: Vec<GFInt>,
+}
+
+impl ResidueSystem {
+    pub fn new(moduli: impl IntoIterator<Item = NonZeroU32>) -> Result<Self, GFError> {
+        let moduli: Vec<_> = moduli.into_iter().collect();
+
+        for (index, a) in moduli.iter().enumerate() {
+            for b in &moduli[index + 1..] {
+                if a.get().gcd(&b.get()) != 1 {
+                    return Err(GFError::NotCoprime(*a, *b));
+                }
+            }
+        }
+
+        Ok(Self {
+            fields: moduli
+                .into_iter()
+                .map(|modulus| GF::new(Some(modulus)))
+                .collect(),
+        })
+    }
+
+    pub fn moduli(&self) -> impl Iterator<Item = NonZeroU32> + '_ {
+        self.fields.iter().map(|field| field.order.unwrap())
+    }
+
+    /// The product of the moduli, which is where values wrap around.
+    /// `None` if it doesn't fit in a `u128`.
+    pub fn modulus(&self) -> Option<u128> {
+        self.moduli().try_fold(1u128, |product, modulus| {
+            product.checked_mul(modulus.get().into())
+        })
+    }
+
+    pub fn create_value(&self, value: u128) -> ResidueInt {
+        ResidueInt {
+            residues: self
+                .fields
+                .iter()
+                .map(|field| {
+                    let order = u128::from(field.order.unwrap().get());
+                    field.create_value((value % order).try_into().unwrap())
+                })
+                .collect(),
+        }
+    }
+}
+
+impl ResidueInt {
+    pub fn system(&self) -> ResidueSystem {
+        ResidueSystem {
+            fields: self.residues.iter().map(|residue| residue.field).collect(),
+        }
+    }
+
+    pub fn residues(&self) -> &[GFInt] {
+        &self.residues
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.residues.iter().all(|residue| residue.value == 0)
+    }
+
+    pub fn square_assign(&mut self) {
+        for residue in self.residues.iter_mut() {
+            residue.square_assign();
+        }
+    }
+
+    /// Whether the integer is divisible by `divisor`. This can only be told
+    /// when the divisor divides the product of the moduli, and is `None` otherwise.
+    pub fn is_divisible_by(&self, divisor: NonZeroU32) -> Option<bool> {
+        // Split the divisor into its factors in each of the moduli
+        let mut remaining = divisor.get();
+        for residue in &self.residues {
+            let factor = remaining.gcd(&residue.field.order.unwrap().get());
+            if residue.value % factor != 0 {
+                return Some(false);
+            }
+            remaining /= factor;
+        }
+        (remaining == 1).then_some(true)
+    }
+
+    /// The smallest integer with these residues, using Garner's algorithm.
+    /// Fails if the product of the moduli doesn't fit in a `u128`,
+    /// even if the integer itself would.
+    pub fn to_u128(&self) -> Result<u128, GFError> {
+        self.system().modulus().ok_or(GFError::Overflow)?;
+
+        // Each step picks the multiple of the product of the moduli so far
+        // that also gives the right residue for the next modulus
+        let mut value: u128 = 0;
+        let mut product: u128 = 1;
+        for residue in &self.residues {
+            let order = residue.field.order.unwrap().get();
+            let wide_order = u128::from(order);
+
+            let current = residue
+                .field
+                .create_value((value % wide_order).try_into().unwrap());
+            let step = residue
+                .field
+                .create_value((product % wide_order).try_into().unwrap());
+            let step_inverse = residue
+                .field
+                .create_value(inverse_mod(step.value, order).unwrap());
+            let count = (*residue - current) * step_inverse;
+
+            value += product * u128::from(count.value);
+            product *= wide_order;
+        }
+
+        Ok(value)
+    }
+}
+
+impl AddAssign<&Self> for ResidueInt {
+    fn add_assign(&mut self, rhs: &Self) {
+        assert_eq!(self.residues.len(), rhs.residues.len());
+
+        for (residue, other) in self.residues.iter_mut().zip(&rhs.residues) {
+            *residue += *other;
+        }
+    }
+}
+
+impl Add for ResidueInt {
+    type Output = Self;
+
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self += &rhs;
+        self
+    }
+}
+
+impl MulAssign<&Self> for ResidueInt {
+    fn mul_assign(&mut self, rhs: &Self) {
+        assert_eq!(self.residues.len(), rhs.residues.len());
+
+        for (residue, other) in self.residues.iter_mut().zip(&rhs.residues) {
+            *residue *= *other;
+        }
+    }
+}
+
+impl Mul for ResidueInt {
+    type Output = Self;
+
+    fn mul(mut self, rhs: Self) -> Self::Output {
+        self *= &rhs;
+        self
+    }
+}
+
+/// An integer modulo `P`, where the modulus is part of the type. Unlike a [`GFInt`],
+/// this is only the value, and mixing different moduli doesn't compile.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ModInt<const P: u32> {
+    value: u32,
+}
+
+impl<const P: u32> ModInt<P> {
+    const MODULUS: NonZeroU32 = match NonZeroU32::new(P) {
+        Some(modulus) => modulus,
+        None => panic!("The modulus must be positive"),
+    };
+
+    pub fn new(value: u32) -> Self {
+        Self {
+            value: value % Self::MODULUS,
+        }
+    }
+
+    pub fn modulus() -> NonZeroU32 {
+        Self::MODULUS
+    }
+
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+
+    pub fn square(&self) -> Self {
+        (*self) * (*self)
+    }
+
+    pub fn square_assign(&mut self) {
+        *self = self.square();
+    }
+
+    /// Raises to the given power by repeated squaring.
+    pub fn pow(&self, exponent: u64) -> Self {
+        power(*self, Self::new(1), exponent)
+    }
+
+    /// The multiplicative inverse. Fails under the same conditions as [`GFInt::inv`].
+    pub fn inv(&self) -> Result<Self, GFError> {
+        let inverse = GFInt::from(*self).inv()?;
+        Ok(Self {
+            value: inverse.value,
+        })
+    }
+}
+
+impl<const P: u32> From<ModInt<P>> for GFInt {
+    fn from(value: ModInt<P>) -> Self {
+        GF::new(Some(ModInt::<P>::MODULUS)).create_value(value.value)
+    }
+}
+
+impl<const P: u32> Add for ModInt<P> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            value: reduce(u64::from(self.value) + u64::from(rhs.value), Self::MODULUS),
+        }
+    }
+}
+
+impl<const P: u32> AddAssign for ModInt<P> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const P: u32> Sub for ModInt<P> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + (-rhs)
+    }
+}
+
+impl<const P: u32> SubAssign for ModInt<P> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const P: u32> Neg for ModInt<P> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(P - self.value)
+    }
+}
+
+impl<const P: u32> Mul for ModInt<P> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self {
+            value: reduce(u64::from(self.value) * u64::from(rhs.value), Self::MODULUS),
+        }
+    }
+}
+
+impl<const P: u32> MulAssign for ModInt<P> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+/// Multiplies by the inverse of the divisor, so fails whenever [`ModInt::inv`] does.
+impl<const P: u32> Div for ModInt<P> {
+    type Output = Result<Self, GFError>;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        rhs.inv().map(|inverse| self * inverse)
+    }
+}
+
+/// An integer that wraps around at 2^32. This is what a [`GFInt`] without an order
+/// does, but without carrying the field around.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct WrappingInt {
+    value: u32,
+}
+
+impl WrappingInt {
+    pub fn new(value: u32) -> Self {
+        Self { value }
+    }
+
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+
+    pub fn square(&self) -> Self {
+        (*self) * (*self)
+    }
+
+    pub fn square_assign(&mut self) {
+        *self = self.square();
+    }
+
+    /// Raises to the given power by repeated squaring.
+    pub fn pow(&self, exponent: u64) -> Self {
+        power(*self, Self::new(1), exponent)
+    }
+}
+
+impl From<WrappingInt> for GFInt {
+    fn from(value: WrappingInt) -> Self {
+        GF::new(None).create_value(value.value)
+    }
+}
+
+impl Add for WrappingInt {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.value.wrapping_add(rhs.value))
+    }
+}
+
+impl AddAssign for WrappingInt {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for WrappingInt {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.value.wrapping_sub(rhs.value))
+    }
+}
+
+impl SubAssign for WrappingInt {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for WrappingInt {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(self.value.wrapping_neg())
+    }
+}
+
+impl Mul for WrappingInt {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(self.value.wrapping_mul(rhs.value))
+    }
+}
+
+impl MulAssign for WrappingInt {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+/// Square-and-multiply, starting from `one`.
+fn power<T: Copy + MulAssign>(mut base: T, one: T, mut exponent: u64) -> T {
+    let mut result = one;
+    while exponent != 0 {
+        if exponent & 1 != 0 {
+            result *= base;
+        }
+        base *= base;
+        exponent >>= 1;
+    }
+    result
+}
+
+/// Reduces a sum or product of two values modulo the order. Since both were
+/// less than the order, it's computed in twice the width, and can't overflow.
+fn reduce(wide: u64, order: NonZeroU32) -> u32 {
+    (wide % u64::from(order.get())).try_into().unwrap()
+}
+
+/// The inverse of `value` modulo `modulus`, by the extended Euclidean algorithm.
+/// `None` if they aren't coprime.
+fn inverse_mod(value: u32, modulus: u32) -> Option<u32> {
+    // Keep r = s * value (mod modulus) for both rows, until r reaches the gcd
+    let (mut r0, mut r1) = (i64::from(modulus), i64::from(value % modulus));
+    let (mut s0, mut s1) = (0i64, 1i64);
+    while r1 != 0 {
+        let quotient = r0 / r1;
+        (r0, r1) = (r1, r0 - quotient * r1);
+        (s0, s1) = (s1, s0 - quotient * s1);
+    }
+
+    (r0 == 1).then(|| s0.rem_euclid(modulus.into()).try_into().unwrap())
+}
+
+/// Deterministic Miller-Rabin. Testing these three bases is enough
+/// for anything that fits in 32 bits.
+fn is_prime(n: u32) -> bool {
+    if n < 2 {
+        return false;
+    }
+    for small in [2, 3, 5, 7, 61] {
+        if n.is_multiple_of(small) {
+            return n == small;
+        }
+    }
+
+    let n = u64::from(n);
+    let power_mod = |mut base: u64, mut exponent: u64| {
+        let mut result = 1;
+        while exponent != 0 {
+            if exponent & 1 != 0 {
+                result = result * base % n;
+            }
+            base = base * base % n;
+            exponent >>= 1;
+        }
+        result
+    };
+
+    let shift = (n - 1).trailing_zeros();
+    let odd = (n - 1) >> shift;
+    [2, 7, 61].into_iter().all(|witness| {
+        let mut x = power_mod(witness, odd);
+        if x == 1 || x == n - 1 {
+            return true;
+        }
+        for _ in 1..shift {
+            x = x * x % n;
+            if x == n - 1 {
+                return true;
+            }
+        }
+        false
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use std::num::NonZeroU32;
 
-    use super::GF;
+    use super::{is_prime, GFError, GFInt, ModInt, ResidueSystem, WrappingInt, GF};
 
     #[test]
     fn addition() {
@@ -114,4 +685,175 @@ fn multiplication() {
         let field = GF::new(NonZeroU32::new(11));
         assert_eq!((field.create_value(6) * field.create_value(5)).value(), 8);
     }
+
+    #[test]
+    fn subtraction_and_negation() {
+        let field = GF::new(NonZeroU32::new(11));
+        assert_eq!((field.create_value(3) - field.create_value(5)).value(), 9);
+        assert_eq!((-field.create_value(0)).value(), 0);
+        assert_eq!((-field.create_value(4)).value(), 7);
+
+        let wrapping = GF::new(None);
+        assert_eq!(
+            (wrapping.create_value(3) - wrapping.create_value(5)).value(),
+            u32::MAX - 1
+        );
+    }
+
+    #[test]
+    fn exponentiation() {
+        let field = GF::new(NonZeroU32::new(13));
+        assert_eq!(field.create_value(2).pow(0).value(), 1);
+        assert_eq!(field.create_value(2).pow(5).value(), 6);
+        for value in 1..13 {
+            assert_eq!(field.create_value(value).pow(12).value(), 1);
+        }
+    }
+
+    #[test]
+    fn inverses() {
+        let field = GF::new(NonZeroU32::new(11));
+        for value in 1..11 {
+            let value = field.create_value(value);
+            assert_eq!((value * value.inv().unwrap()).value(), 1);
+        }
+        assert_eq!(
+            (field.create_value(3) / field.create_value(7)).map(|value| value.value()),
+            Ok(2)
+        );
+
+        let zero = field.create_value(0);
+        assert_eq!(zero.inv(), Err(GFError::NotInvertible(zero)));
+
+        let ring = GF::new(NonZeroU32::new(10));
+        assert_eq!(ring.create_value(3).inv(), Err(GFError::NotAField(ring)));
+        assert!(GF::new(None).create_value(3).inv().is_err());
+    }
+
+    #[test]
+    fn large_moduli() {
+        let field = GF::new(NonZeroU32::new(u32::MAX - 4));
+        let minus_one = field.create_value(u32::MAX - 5);
+        assert_eq!((minus_one + minus_one).value(), u32::MAX - 6);
+        assert_eq!((minus_one * minus_one).value(), 1);
+        assert_eq!(minus_one.square().value(), 1);
+        assert_eq!(
+            field.create_value(3).pow(u64::from(u32::MAX - 5)).value(),
+            1
+        );
+        assert_eq!(
+            (field.create_value(1) / minus_one).map(|value| value.value()),
+            Ok(u32::MAX - 5)
+        );
+    }
+
+    #[test]
+    fn compile_time_moduli() {
+        type F = ModInt<11>;
+        assert_eq!((F::new(6) + F::new(5)).value(), 0);
+        assert_eq!((F::new(3) - F::new(5)).value(), 9);
+        assert_eq!((-F::new(0)).value(), 0);
+        assert_eq!((F::new(6) * F::new(5)).value(), 8);
+        assert_eq!((F::new(3) / F::new(7)).map(|value| value.value()), Ok(2));
+        assert_eq!(F::new(2).pow(10), F::new(1));
+        assert_eq!(
+            GFInt::from(F::new(4)),
+            GF::new(F::modulus().into()).create_value(4)
+        );
+
+        type Large = ModInt<{ u32::MAX - 4 }>;
+        assert_eq!(Large::new(u32::MAX - 5).square().value(), 1);
+
+        let ring = GF::new(ModInt::<10>::modulus().into());
+        assert_eq!(ModInt::<10>::new(3).inv(), Err(GFError::NotAField(ring)));
+    }
+
+    #[test]
+    fn wrapping() {
+        assert_eq!(
+            (WrappingInt::new(3) - WrappingInt::new(5)).value(),
+            u32::MAX - 1
+        );
+        assert_eq!((-WrappingInt::new(1)).value(), u32::MAX);
+        assert_eq!(WrappingInt::new(1 << 16).square().value(), 0);
+        assert_eq!(WrappingInt::new(3).pow(4).value(), 81);
+    }
+
+    #[test]
+    fn primes() {
+        let primes: Vec<_> = (0..50).filter(|&n| is_prime(n)).collect();
+        assert_eq!(
+            primes,
+            [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47]
+        );
+        assert!(is_prime(4_294_967_291));
+        assert!(!is_prime(3_215_031_751));
+    }
+
+    #[test]
+    fn residues() {
+        let moduli = [3, 5, 8].map(|modulus| NonZeroU32::new(modulus).unwrap());
+        let system = ResidueSystem::new(moduli).unwrap();
+        assert_eq!(system.modulus(), Some(120));
+
+        for a in 0..120 {
+            let x = system.create_value(a);
+            assert_eq!(x.to_u128(), Ok(a));
+            for divisor in 1..=12 {
+                // Divisors of 120 can always be told, and others only sometimes
+                let divisible = x.is_divisible_by(NonZeroU32::new(divisor).unwrap());
+                if 120 % divisor == 0 {
+                    assert!(divisible.is_some());
+                }
+                if let Some(divisible) = divisible {
+                    assert_eq!(
+                        divisible,
+                        a % u128::from(divisor) == 0,
+                        "{} / {}",
+                        a,
+                        divisor
+                    );
+                }
+            }
+
+            let y = system.create_value(77);
+            assert_eq!((x.clone() + y.clone()).to_u128(), Ok((a + 77) % 120));
+            assert_eq!((x * y).to_u128(), Ok(a * 77 % 120));
+        }
+
+        let large = ResidueSystem::new(
+            [u32::MAX - 4, u32::MAX - 2, u32::MAX].map(|modulus| NonZeroU32::new(modulus).unwrap()),
+        )
+        .unwrap();
+        let value = 12_345_678_901_234_567_890_123_456_789;
+        assert_eq!(large.create_value(value).to_u128(), Ok(value));
+        let sum = large.create_value(value) + large.create_value(value);
+        assert_eq!(sum.to_u128(), Ok(2 * value));
+        let product = large.create_value(value) * large.create_value(1000);
+        assert_eq!(
+            product.to_u128(),
+            Ok(value * 1000 % large.modulus().unwrap())
+        );
+    }
+
+    #[test]
+    fn residue_errors() {
+        let moduli = [6, 35, 4].map(|modulus| NonZeroU32::new(modulus).unwrap());
+        assert_eq!(
+            ResidueSystem::new(moduli),
+            Err(GFError::NotCoprime(moduli[0], moduli[2]))
+        );
+
+        let primes = [
+            4_294_967_291,
+            4_294_967_279,
+            4_294_967_231,
+            4_294_967_197,
+            4_294_967_189,
+        ];
+        let system =
+            ResidueSystem::new(primes.map(|prime| NonZeroU32::new(prime).unwrap())).unwrap();
+        assert_eq!(system.modulus(), None);
+        assert_eq!(system.create_value(1).to_u128(), Err(GFError::Overflow));
+    }
 }
@@ -0,0 +1,553 @@
+//! Galois fields with a prime power number of elements.
+
+use std::{
+    fmt::Display,
+    num::NonZeroU32,
+    ops::{Add, AddAssign, BitXor, Div, Mul, MulAssign, Neg, Sub, SubAssign},
+    rc::Rc,
+};
+
+use super::{GFError, GFInt, Polynomial, GF};
+
+/// The field GF(p^n): polynomials with coefficients modulo a prime p,
+/// themselves taken modulo an irreducible polynomial of degree n.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExtensionField {
+    base: GF,
+    /// The irreducible polynomial, made monic.
+    modulus: Rc<Polynomial>,
+}
+
+/// An element of an [`ExtensionField`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExtensionInt {
+    field: ExtensionField,
+    /// Least significant first, exactly as many as the degree of the field.
+    coefficients: Vec<GFInt>,
+}
+
+impl ExtensionField {
+    /// Creates the field over the integers modulo `prime`, with the polynomial
+    /// whose coefficients are given, least significant first. The polynomial
+    /// is divided by its leading coefficient, so it doesn't need to be monic.
+    ///
+    /// Fails if `prime` isn't prime, or the polynomial can be factored.
+    pub fn new(prime: NonZeroU32, modulus: &[u32]) -> Result<Self, GFError> {
+        let base = GF::new(Some(prime));
+        if !base.is_field() {
+            return Err(GFError::NotAField(base));
+        }
+
+        // Constants don't have factors, but don't make fields either
+        let modulus = Polynomial::new(base, modulus);
+        if modulus.degree().unwrap_or(0) == 0 {
+            return Err(GFError::NotIrreducible);
+        }
+        let modulus = modulus.monic()?;
+
+        if !is_irreducible(&modulus)? {
+            return Err(GFError::NotIrreducible);
+        }
+
+        Ok(Self {
+            base,
+            modulus: modulus.into(),
+        })
+    }
+
+    /// The integers modulo the prime, which the coefficients are taken from.
+    pub fn base(&self) -> GF {
+        self.base
+    }
+
+    /// The irreducible polynomial, divided by its leading coefficient.
+    pub fn modulus(&self) -> &Polynomial {
+        &self.modulus
+    }
+
+    /// The n in GF(p^n).
+    pub fn degree(&self) -> usize {
+        self.modulus.degree().unwrap()
+    }
+
+    /// The number of elements, if it fits in a `u128`.
+    pub fn order(&self) -> Option<u128> {
+        let prime = u128::from(self.base.order().unwrap().get());
+        prime.checked_pow(self.degree().try_into().ok()?)
+    }
+
+    /// Creates the element with the given coefficients, least significant first.
+    /// There can be more of them than the degree of the field.
+    pub fn create_value(&self, coefficients: &[u32]) -> ExtensionInt {
+        self.reduce(&Polynomial::new(self.base, coefficients))
+    }
+
+    pub fn zero(&self) -> ExtensionInt {
+        self.create_value(&[])
+    }
+
+    pub fn one(&self) -> ExtensionInt {
+        self.create_value(&[1])
+    }
+
+    fn reduce(&self, polynomial: &Polynomial) -> ExtensionInt {
+        let (_, remainder) = polynomial
+            .div_rem(&self.modulus)
+            .expect("The modulus is monic");
+        let mut coefficients = remainder.coefficients().to_vec();
+        coefficients.resize(self.degree(), self.base.create_value(0));
+        ExtensionInt {
+            field: self.clone(),
+            coefficients,
+        }
+    }
+}
+
+impl ExtensionInt {
+    pub fn field(&self) -> &ExtensionField {
+        &self.field
+    }
+
+    /// Least significant first, as many as the degree of the field.
+    pub fn coefficients(&self) -> &[GFInt] {
+        &self.coefficients
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.coefficients.iter().all(|c| c.value() == 0)
+    }
+
+    /// The polynomial this element is represented by.
+    pub fn polynomial(&self) -> Polynomial {
+        Polynomial::from_values(self.field.base, self.coefficients.clone())
+    }
+
+    /// Raises to the given power by repeated squaring.
+    pub fn pow(&self, mut exponent: u64) -> Self {
+        let mut result = self.field.one();
+        let mut base = self.clone();
+        while exponent != 0 {
+            if exponent & 1 != 0 {
+                result *= &base;
+            }
+            base = &base * &base;
+            exponent >>= 1;
+        }
+        result
+    }
+
+    /// The multiplicative inverse, found with the extended Euclidean algorithm
+    /// on polynomials.
+    ///
+    /// Fails if the value is zero.
+    pub fn inv(&self) -> Result<Self, GFError> {
+        if self.is_zero() {
+            return Err(GFError::DivisionByZero);
+        }
+
+        let base = self.field.base;
+        let (mut r0, mut r1) = ((*self.field.modulus).clone(), self.polynomial());
+        let (mut s0, mut s1) = (Polynomial::zero(base), Polynomial::one(base));
+        while !r1.is_zero() {
+            let (quotient, remainder) = r0.div_rem(&r1)?;
+            let s2 = &s0 - &(&quotient * &s1);
+            (r0, r1) = (r1, remainder);
+            (s0, s1) = (s1, s2);
+        }
+
+        // The modulus is irreducible, so the gcd is a nonzero constant
+        let gcd_inverse = r0.coefficients()[0].inv()?;
+        let inverse = &s0 * &Polynomial::from_values(base, vec![gcd_inverse]);
+        Ok(self.field.reduce(&inverse))
+    }
+}
+
+impl AddAssign<&Self> for ExtensionInt {
+    fn add_assign(&mut self, rhs: &Self) {
+        assert_eq!(self.field, rhs.field);
+
+        for (c, &other) in self.coefficients.iter_mut().zip(&rhs.coefficients) {
+            *c += other;
+        }
+    }
+}
+
+impl Add for &ExtensionInt {
+    type Output = ExtensionInt;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let mut result = self.clone();
+        result += rhs;
+        result
+    }
+}
+
+impl SubAssign<&Self> for ExtensionInt {
+    fn sub_assign(&mut self, rhs: &Self) {
+        assert_eq!(self.field, rhs.field);
+
+        for (c, &other) in self.coefficients.iter_mut().zip(&rhs.coefficients) {
+            *c -= other;
+        }
+    }
+}
+
+impl Sub for &ExtensionInt {
+    type Output = ExtensionInt;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let mut result = self.clone();
+        result -= rhs;
+        result
+    }
+}
+
+impl Neg for &ExtensionInt {
+    type Output = ExtensionInt;
+
+    fn neg(self) -> Self::Output {
+        ExtensionInt {
+            field: self.field.clone(),
+            coefficients: self.coefficients.iter().map(|&c| -c).collect(),
+        }
+    }
+}
+
+impl MulAssign<&Self> for ExtensionInt {
+    fn mul_assign(&mut self, rhs: &Self) {
+        *self = &*self * rhs;
+    }
+}
+
+impl Mul for &ExtensionInt {
+    type Output = ExtensionInt;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        assert_eq!(self.field, rhs.field);
+
+        self.field.reduce(&(&self.polynomial() * &rhs.polynomial()))
+    }
+}
+
+/// Multiplies by the inverse of the divisor, so fails if it's zero.
+impl Div for &ExtensionInt {
+    type Output = Result<ExtensionInt, GFError>;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        rhs.inv().map(|inverse| self * &inverse)
+    }
+}
+
+/// Writes the polynomial from the highest power of x down, such as `2x^2 + x + 1`.
+impl Display for ExtensionInt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.polynomial())
+    }
+}
+
+/// An element of GF(2^8), the field most checksums and error-correcting codes use.
+/// Each element is a byte, whose bits are the coefficients of a polynomial over GF(2),
+/// taken modulo `POLYNOMIAL`. The default is the one Reed-Solomon codes usually use.
+///
+/// Multiplication goes through tables of logarithms, which are built
+/// at compile time.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct GF256Int<const POLYNOMIAL: u16 = 0x11d> {
+    value: u8,
+}
+
+impl<const POLYNOMIAL: u16> GF256Int<POLYNOMIAL> {
+    const TABLES: LogTables = LogTables::new(POLYNOMIAL);
+
+    pub fn new(value: u8) -> Self {
+        Self { value }
+    }
+
+    pub fn value(&self) -> u8 {
+        self.value
+    }
+
+    /// An element whose powers are all the nonzero elements.
+    pub fn generator() -> Self {
+        Self::new(Self::TABLES.generator)
+    }
+
+    /// The power the generator has to be raised to to get this element.
+    /// `None` for zero.
+    pub fn log(&self) -> Option<u8> {
+        (self.value != 0).then(|| Self::TABLES.log[usize::from(self.value)])
+    }
+
+    pub fn pow(&self, exponent: u64) -> Self {
+        match self.log() {
+            None if exponent == 0 => Self::new(1),
+            None => *self,
+            Some(log) => {
+                let exponent = exponent % 255;
+                Self::from_log(u64::from(log) * exponent)
+            }
+        }
+    }
+
+    /// Fails if the value is zero.
+    pub fn inv(&self) -> Result<Self, GFError> {
+        let log = self.log().ok_or(GFError::DivisionByZero)?;
+        Ok(Self::from_log(255 - u64::from(log)))
+    }
+
+    fn from_log(log: u64) -> Self {
+        Self::new(Self::TABLES.exp[usize::try_from(log % 255).unwrap()])
+    }
+
+    /// The product of the elements with these logarithms.
+    fn from_logs(a: u8, b: u8) -> Self {
+        Self::from_log(u64::from(a) + u64::from(b))
+    }
+}
+
+impl<const POLYNOMIAL: u16> Add for GF256Int<POLYNOMIAL> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        // Coefficients are added modulo 2
+        Self::new(self.value.bitxor(rhs.value))
+    }
+}
+
+impl<const POLYNOMIAL: u16> AddAssign for GF256Int<POLYNOMIAL> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const POLYNOMIAL: u16> Sub for GF256Int<POLYNOMIAL> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + (-rhs)
+    }
+}
+
+impl<const POLYNOMIAL: u16> SubAssign for GF256Int<POLYNOMIAL> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const POLYNOMIAL: u16> Neg for GF256Int<POLYNOMIAL> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        // Every element is its own negation in characteristic 2
+        self
+    }
+}
+
+impl<const POLYNOMIAL: u16> Mul for GF256Int<POLYNOMIAL> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        match (self.log(), rhs.log()) {
+            (Some(a), Some(b)) => Self::from_logs(a, b),
+            _ => Self::new(0),
+        }
+    }
+}
+
+impl<const POLYNOMIAL: u16> MulAssign for GF256Int<POLYNOMIAL> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+/// Multiplies by the inverse of the divisor, so fails if it's zero.
+impl<const POLYNOMIAL: u16> Div for GF256Int<POLYNOMIAL> {
+    type Output = Result<Self, GFError>;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        rhs.inv().map(|inverse| self * inverse)
+    }
+}
+
+struct LogTables {
+    generator: u8,
+    /// The powers of the generator.
+    exp: [u8; 255],
+    /// The inverse of `exp`. Zero has no logarithm, so its entry is unused.
+    log: [u8; 256],
+}
+
+impl LogTables {
+    /// Tries every element as the generator, until one whose powers go
+    /// through all 255 nonzero elements before coming back to 1.
+    /// That only happens if the polynomial is irreducible.
+    const fn new(polynomial: u16) -> Self {
+        assert!(polynomial >> 8 == 1, "The polynomial must have degree 8");
+
+        let mut generator = 2;
+        loop {
+            let mut tables = Self {
+                generator,
+                exp: [0; 255],
+                log: [0; 256],
+            };
+
+            let mut value = 1;
+            let mut power = 0;
+            while power < 255 {
+                if power > 0 && value == 1 {
+                    break;
+                }
+                tables.exp[power] = value;
+                tables.log[value as usize] = power as u8;
+                value = carryless_multiply(value, generator, polynomial);
+                power += 1;
+            }
+            if power == 255 && value == 1 {
+                return tables;
+            }
+
+            assert!(generator < 255, "The polynomial isn't irreducible");
+            generator += 1;
+        }
+    }
+}
+
+/// Multiplies bit by bit, reducing modulo the polynomial whenever the product
+/// reaches degree 8.
+const fn carryless_multiply(mut a: u8, mut b: u8, polynomial: u16) -> u8 {
+    let reduction = polynomial as u8;
+    let mut product = 0;
+    while b != 0 {
+        if b & 1 != 0 {
+            product ^= a;
+        }
+        let overflow = a & 0x80 != 0;
+        a <<= 1;
+        if overflow {
+            a ^= reduction;
+        }
+        b >>= 1;
+    }
+    product
+}
+
+/// Ben-Or's test: a polynomial of degree n is irreducible if it has no common
+/// factor with x^(p^i) - x for any i up to n / 2. That polynomial is the product
+/// of all the irreducible polynomials whose degree divides i.
+fn is_irreducible(polynomial: &Polynomial) -> Result<bool, GFError> {
+    let base = polynomial.field();
+    let degree = polynomial.degree().unwrap();
+    let prime = base.order().unwrap().get();
+    let x = Polynomial::x(base);
+
+    let mut power = x.clone();
+    for _ in 1..=degree / 2 {
+        power = power.pow_mod(prime.into(), polynomial)?;
+        if polynomial.gcd(&(&power - &x))?.degree() != Some(0) {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroU32;
+
+    use itertools::iproduct;
+
+    use super::{ExtensionField, GF256Int};
+    use crate::galois::GFError;
+
+    type Aes = GF256Int<0x11b>;
+
+    #[test]
+    fn gf256() {
+        // From FIPS 197
+        assert_eq!(Aes::new(0x57) * Aes::new(0x83), Aes::new(0xc1));
+        assert_eq!(Aes::new(0x53).inv(), Ok(Aes::new(0xca)));
+        assert_eq!(Aes::new(0x57) + Aes::new(0x83), Aes::new(0xd4));
+        assert_eq!(Aes::generator(), Aes::new(3));
+
+        type Rs = GF256Int;
+        assert_eq!(Rs::generator(), Rs::new(2));
+        assert_eq!(Rs::new(2).pow(8), Rs::new(0x1d));
+        assert_eq!(Rs::new(0).pow(0), Rs::new(1));
+        assert_eq!(Rs::new(0).inv(), Err(GFError::DivisionByZero));
+        for value in 1..=255 {
+            let value = Rs::new(value);
+            assert_eq!(value * value.inv().unwrap(), Rs::new(1));
+            assert_eq!(value.pow(255), Rs::new(1));
+        }
+    }
+
+    #[test]
+    fn extension_matches_tables() {
+        let two = NonZeroU32::new(2).unwrap();
+        let field = ExtensionField::new(two, &[1, 1, 0, 1, 1, 0, 0, 0, 1]).unwrap();
+        assert_eq!(field.order(), Some(256));
+
+        let element = |value: u8| {
+            let bits = (0..8)
+                .map(|bit| u32::from(value >> bit & 1))
+                .collect::<Vec<_>>();
+            field.create_value(&bits)
+        };
+        for (a, b) in iproduct!(0..=255, [0, 1, 2, 0x53, 0x83, 0xff]) {
+            assert_eq!(
+                &element(a) * &element(b),
+                element((Aes::new(a) * Aes::new(b)).value())
+            );
+        }
+        assert_eq!(element(0x53).inv(), Ok(element(0xca)));
+    }
+
+    #[test]
+    fn extension_of_odd_prime() {
+        // x^2 + 1 has no roots modulo 3, so GF(9) is built on it
+        let three = NonZeroU32::new(3).unwrap();
+        let field = ExtensionField::new(three, &[1, 0, 1]).unwrap();
+        assert_eq!(field.degree(), 2);
+
+        let i = field.create_value(&[0, 1]);
+        assert_eq!(&i * &i, field.create_value(&[2]));
+        assert_eq!(i.pow(4), field.one());
+        assert_eq!(field.create_value(&[1, 2]).to_string(), "2x + 1");
+        assert_eq!(field.create_value(&[0, 0, 1]).to_string(), "2");
+
+        for (a, b) in iproduct!(0..3, 0..3) {
+            let value = field.create_value(&[a, b]);
+            match value.inv() {
+                Ok(inverse) => assert_eq!(&value * &inverse, field.one()),
+                Err(error) => {
+                    assert!(value.is_zero());
+                    assert_eq!(error, GFError::DivisionByZero);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn invalid_fields() {
+        let three = NonZeroU32::new(3).unwrap();
+        // (x + 1)^2
+        assert_eq!(
+            ExtensionField::new(three, &[1, 2, 1]),
+            Err(GFError::NotIrreducible)
+        );
+        // (x^2 + 1)^2, which has no roots
+        assert_eq!(
+            ExtensionField::new(three, &[1, 0, 2, 0, 1]),
+            Err(GFError::NotIrreducible)
+        );
+        assert_eq!(
+            ExtensionField::new(three, &[2]),
+            Err(GFError::NotIrreducible)
+        );
+        assert!(matches!(
+            ExtensionField::new(NonZeroU32::new(4).unwrap(), &[1, 1, 1]),
+            Err(GFError::NotAField(_))
+        ));
+    }
+}
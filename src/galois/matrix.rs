@@ -0,0 +1,353 @@
+//! Linear algebra over the integers modulo a prime.
+
+use std::ops::{Add, Index, Mul, Sub};
+
+use ndarray::{concatenate, s, Array2, ArrayView2, Axis};
+
+use super::{GFError, GFInt, GF};
+
+/// A matrix whose elements are all in the same [`GF`]. Anything that needs
+/// division, such as Gaussian elimination, needs the order of the field to be prime.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Matrix {
+    field: GF,
+    elements: Array2<GFInt>,
+}
+
+/// What's left after Gauss-Jordan elimination.
+struct Elimination {
+    /// The column of the pivot of each nonzero row, in order.
+    pivots: Vec<usize>,
+    /// The product of the pivots, negated for every swap of rows.
+    /// For a square matrix of full rank, that's the determinant.
+    determinant: GFInt,
+}
+
+impl Matrix {
+    /// Wraps an array indexed by (row, column), taking every element modulo the order.
+    pub fn new(field: GF, elements: Array2<u32>) -> Self {
+        Self {
+            field,
+            elements: elements.map(|&element| field.create_value(element)),
+        }
+    }
+
+    pub fn from_values(field: GF, elements: Array2<GFInt>) -> Self {
+        assert!(elements.iter().all(|element| element.field() == field));
+
+        Self { field, elements }
+    }
+
+    pub fn zeros(field: GF, rows: usize, columns: usize) -> Self {
+        Self::new(field, Array2::zeros((rows, columns)))
+    }
+
+    pub fn identity(field: GF, size: usize) -> Self {
+        Self::new(field, Array2::eye(size))
+    }
+
+    pub fn field(&self) -> GF {
+        self.field
+    }
+
+    pub fn nrows(&self) -> usize {
+        self.elements.nrows()
+    }
+
+    pub fn ncols(&self) -> usize {
+        self.elements.ncols()
+    }
+
+    /// The underlying array, indexed by (row, column).
+    pub fn view(&self) -> ArrayView2<'_, GFInt> {
+        self.elements.view()
+    }
+
+    pub fn transpose(&self) -> Self {
+        Self::from_values(self.field, self.elements.t().to_owned())
+    }
+
+    /// Multiplies by a column vector.
+    pub fn apply(&self, vector: &[GFInt]) -> Vec<GFInt> {
+        assert_eq!(self.ncols(), vector.len());
+
+        self.elements
+            .rows()
+            .into_iter()
+            .map(|row| {
+                row.iter()
+                    .zip(vector)
+                    .fold(self.field.create_value(0), |acc, (&a, &b)| acc + a * b)
+            })
+            .collect()
+    }
+
+    /// The reduced row echelon form, found by Gauss-Jordan elimination.
+    ///
+    /// Fails if the order of the field isn't prime.
+    pub fn reduced_row_echelon(&self) -> Result<Self, GFError> {
+        let mut result = self.clone();
+        result.eliminate(self.ncols())?;
+        Ok(result)
+    }
+
+    /// Fails if the order of the field isn't prime.
+    pub fn rank(&self) -> Result<usize, GFError> {
+        Ok(self.clone().eliminate(self.ncols())?.pivots.len())
+    }
+
+    /// Fails if the order of the field isn't prime.
+    ///
+    /// # Panics
+    ///
+    /// If the matrix isn't square.
+    pub fn determinant(&self) -> Result<GFInt, GFError> {
+        assert_eq!(self.nrows(), self.ncols(), "Expected a square matrix");
+
+        let elimination = self.clone().eliminate(self.ncols())?;
+        if elimination.pivots.len() < self.nrows() {
+            return Ok(self.field.create_value(0));
+        }
+        Ok(elimination.determinant)
+    }
+
+    /// Eliminates the matrix next to the identity, which turns the identity
+    /// into the inverse.
+    ///
+    /// Fails if the order of the field isn't prime, or the matrix is singular.
+    ///
+    /// # Panics
+    ///
+    /// If the matrix isn't square.
+    pub fn inverse(&self) -> Result<Self, GFError> {
+        assert_eq!(self.nrows(), self.ncols(), "Expected a square matrix");
+        let size = self.nrows();
+
+        let identity = Self::identity(self.field, size);
+        let mut augmented = self.augment(&identity.elements);
+        if augmented.eliminate(size)?.pivots.len() < size {
+            return Err(GFError::Singular);
+        }
+
+        Ok(Self::from_values(
+            self.field,
+            augmented.elements.slice(s![.., size..]).to_owned(),
+        ))
+    }
+
+    /// Finds an `x` such that multiplying the matrix by it gives `b`. If there are
+    /// several, the variables that can take any value are set to zero.
+    ///
+    /// Fails if the order of the field isn't prime, or there's no solution.
+    pub fn solve(&self, b: &[GFInt]) -> Result<Vec<GFInt>, GFError> {
+        assert_eq!(self.nrows(), b.len());
+
+        let column = Array2::from_shape_vec((b.len(), 1), b.to_vec()).unwrap();
+        let mut augmented = self.augment(&column);
+        let pivots = augmented.eliminate(self.ncols())?.pivots;
+
+        // A row that's all zeros except for b reads 0 = b
+        let consistent = augmented
+            .elements
+            .rows()
+            .into_iter()
+            .skip(pivots.len())
+            .all(|row| row[self.ncols()].value() == 0);
+        if !consistent {
+            return Err(GFError::NoSolution);
+        }
+
+        let mut solution = vec![self.field.create_value(0); self.ncols()];
+        for (row, &column) in pivots.iter().enumerate() {
+            solution[column] = augmented.elements[(row, self.ncols())];
+        }
+        Ok(solution)
+    }
+
+    fn augment(&self, columns: &Array2<GFInt>) -> Self {
+        Self::from_values(
+            self.field,
+            concatenate(Axis(1), &[self.elements.view(), columns.view()]).unwrap(),
+        )
+    }
+
+    /// Brings the first `columns` columns to reduced row echelon form,
+    /// applying the same row operations to the rest.
+    fn eliminate(&mut self, columns: usize) -> Result<Elimination, GFError> {
+        if !self.field.is_field() {
+            return Err(GFError::NotAField(self.field));
+        }
+
+        let mut pivots = vec![];
+        let mut determinant = self.field.create_value(1);
+        for column in 0..columns {
+            let row = pivots.len();
+            let Some(pivot_row) =
+                (row..self.nrows()).find(|&r| self.elements[(r, column)].value() != 0)
+            else {
+                continue;
+            };
+
+            if pivot_row != row {
+                for c in 0..self.ncols() {
+                    self.elements.swap((row, c), (pivot_row, c));
+                }
+                determinant = -determinant;
+            }
+
+            let pivot = self.elements[(row, column)];
+            determinant *= pivot;
+            let pivot_inverse = pivot.inv()?;
+            self.elements
+                .row_mut(row)
+                .map_inplace(|element| *element *= pivot_inverse);
+
+            let pivot_values = self.elements.row(row).to_owned();
+            for (r, mut other) in self.elements.rows_mut().into_iter().enumerate() {
+                let factor = other[column];
+                if r != row && factor.value() != 0 {
+                    other.zip_mut_with(&pivot_values, |element, &pivot_value| {
+                        *element -= factor * pivot_value
+                    });
+                }
+            }
+
+            pivots.push(column);
+        }
+
+        Ok(Elimination {
+            pivots,
+            determinant,
+        })
+    }
+}
+
+impl Index<(usize, usize)> for Matrix {
+    type Output = GFInt;
+
+    /// Indexed by (row, column).
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        &self.elements[index]
+    }
+}
+
+impl Add for &Matrix {
+    type Output = Matrix;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        assert_eq!(self.field, rhs.field);
+
+        let mut elements = self.elements.clone();
+        elements.zip_mut_with(&rhs.elements, |a, &b| *a += b);
+        Matrix::from_values(self.field, elements)
+    }
+}
+
+impl Sub for &Matrix {
+    type Output = Matrix;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        assert_eq!(self.field, rhs.field);
+
+        let mut elements = self.elements.clone();
+        elements.zip_mut_with(&rhs.elements, |a, &b| *a -= b);
+        Matrix::from_values(self.field, elements)
+    }
+}
+
+impl Mul for &Matrix {
+    type Output = Matrix;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        assert_eq!(self.field, rhs.field);
+        assert_eq!(self.ncols(), rhs.nrows());
+
+        let elements = Array2::from_shape_fn((self.nrows(), rhs.ncols()), |(row, column)| {
+            self.elements
+                .row(row)
+                .iter()
+                .zip(rhs.elements.column(column))
+                .fold(self.field.create_value(0), |acc, (&a, &b)| acc + a * b)
+        });
+        Matrix::from_values(self.field, elements)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroU32;
+
+    use ndarray::array;
+
+    use super::Matrix;
+    use crate::galois::{GFError, GF};
+
+    fn field(order: u32) -> GF {
+        GF::new(NonZeroU32::new(order))
+    }
+
+    #[test]
+    fn inverse_and_determinant() {
+        let f = field(7);
+        let matrix = Matrix::new(f, array![[2, 1, 0], [1, 3, 4], [0, 5, 6]]);
+        // 2 (18 - 20) - (6 - 0) = -10
+        assert_eq!(matrix.determinant(), Ok(f.create_value(4)));
+        assert_eq!(matrix.rank(), Ok(3));
+
+        let inverse = matrix.inverse().unwrap();
+        assert_eq!(&matrix * &inverse, Matrix::identity(f, 3));
+        assert_eq!(&inverse * &matrix, Matrix::identity(f, 3));
+
+        // Needs a swap to find the first pivot
+        let swapped = Matrix::new(f, array![[0, 1], [1, 0]]);
+        assert_eq!(swapped.determinant(), Ok(f.create_value(6)));
+        assert_eq!(swapped.inverse(), Ok(swapped.clone()));
+    }
+
+    #[test]
+    fn singular() {
+        let f = field(5);
+        let matrix = Matrix::new(f, array![[1, 2, 3], [2, 4, 6], [0, 1, 1]]);
+        assert_eq!(matrix.rank(), Ok(2));
+        assert_eq!(matrix.determinant(), Ok(f.create_value(0)));
+        assert_eq!(matrix.inverse(), Err(GFError::Singular));
+        assert_eq!(
+            matrix.reduced_row_echelon(),
+            Ok(Matrix::new(f, array![[1, 0, 1], [0, 1, 1], [0, 0, 0]]))
+        );
+
+        let ring = Matrix::new(field(4), array![[1, 0], [0, 1]]);
+        assert_eq!(ring.rank(), Err(GFError::NotAField(field(4))));
+    }
+
+    #[test]
+    fn linear_systems() {
+        let f = field(11);
+        let values = |values: &[u32]| {
+            values
+                .iter()
+                .map(|&v| f.create_value(v))
+                .collect::<Vec<_>>()
+        };
+
+        let matrix = Matrix::new(f, array![[1, 2, 3], [4, 5, 6], [7, 8, 10]]);
+        let x = values(&[3, 1, 4]);
+        let b = matrix.apply(&x);
+        assert_eq!(matrix.solve(&b), Ok(x));
+
+        // x + 2y = 3 has solutions, with y free
+        let underdetermined = Matrix::new(f, array![[1, 2], [2, 4]]);
+        assert_eq!(underdetermined.solve(&values(&[3, 6])), Ok(values(&[3, 0])));
+        assert_eq!(
+            underdetermined.solve(&values(&[3, 7])),
+            Err(GFError::NoSolution)
+        );
+
+        assert_eq!(matrix.transpose().transpose(), matrix);
+        assert_eq!(&(&matrix + &matrix) - &matrix, matrix);
+        assert_eq!(
+            Matrix::zeros(f, 2, 3).apply(&values(&[1, 2, 3])),
+            values(&[0, 0])
+        );
+    }
+}
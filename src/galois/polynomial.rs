@@ -0,0 +1,398 @@
+//! Polynomials with coefficients in the integers modulo some number.
+
+use std::{
+    fmt::Display,
+    ops::{Add, Mul, Neg, Sub},
+};
+
+use itertools::{EitherOrBoth, Itertools};
+
+use super::{GFError, GFInt, GF};
+
+/// A polynomial whose coefficients are all in the same [`GF`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polynomial {
+    field: GF,
+    /// Least significant first, without leading zeros.
+    /// Zero has no coefficients at all.
+    coefficients: Vec<GFInt>,
+}
+
+impl Polynomial {
+    /// Creates the polynomial with the given coefficients, least significant first.
+    pub fn new(field: GF, coefficients: &[u32]) -> Self {
+        Self::from_values(
+            field,
+            coefficients
+                .iter()
+                .map(|&c| field.create_value(c))
+                .collect(),
+        )
+    }
+
+    /// Like [`Polynomial::new`], with coefficients that are already in the field.
+    pub fn from_values(field: GF, coefficients: Vec<GFInt>) -> Self {
+        assert!(coefficients.iter().all(|c| c.field() == field));
+
+        let mut polynomial = Self {
+            field,
+            coefficients,
+        };
+        polynomial.trim();
+        polynomial
+    }
+
+    pub fn zero(field: GF) -> Self {
+        Self::new(field, &[])
+    }
+
+    pub fn one(field: GF) -> Self {
+        Self::new(field, &[1])
+    }
+
+    /// The polynomial `x`.
+    pub fn x(field: GF) -> Self {
+        Self::new(field, &[0, 1])
+    }
+
+    pub fn field(&self) -> GF {
+        self.field
+    }
+
+    /// Least significant first, up to the last nonzero one.
+    pub fn coefficients(&self) -> &[GFInt] {
+        &self.coefficients
+    }
+
+    /// `None` for zero, whose degree is undefined.
+    pub fn degree(&self) -> Option<usize> {
+        self.coefficients.len().checked_sub(1)
+    }
+
+    pub fn leading_coefficient(&self) -> Option<GFInt> {
+        self.coefficients.last().copied()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.coefficients.is_empty()
+    }
+
+    /// Evaluates at `x` with Horner's method.
+    pub fn eval(&self, x: GFInt) -> GFInt {
+        assert_eq!(self.field, x.field());
+
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(self.field.create_value(0), |acc, &c| acc * x + c)
+    }
+
+    /// Divides by the leading coefficient.
+    ///
+    /// Fails if the polynomial is zero, or its leading coefficient has no inverse.
+    pub fn monic(&self) -> Result<Self, GFError> {
+        let leading = self.leading_coefficient().ok_or(GFError::DivisionByZero)?;
+        if leading.value() == 1 {
+            return Ok(self.clone());
+        }
+        Ok(self.scale(leading.inv()?))
+    }
+
+    /// Long division, returning the quotient and the remainder. The remainder's
+    /// degree is less than that of `rhs`.
+    ///
+    /// Fails if `rhs` is zero, or its leading coefficient has no inverse.
+    /// Monic divisors always work, even when the coefficients aren't in a field.
+    pub fn div_rem(&self, rhs: &Self) -> Result<(Self, Self), GFError> {
+        assert_eq!(self.field, rhs.field);
+
+        let leading = rhs.leading_coefficient().ok_or(GFError::DivisionByZero)?;
+        let leading_inverse = if leading.value() == 1 {
+            leading
+        } else {
+            leading.inv()?
+        };
+
+        let divisor = &rhs.coefficients;
+        let mut remainder = self.coefficients.clone();
+        let mut quotient =
+            vec![self.field.create_value(0); (remainder.len() + 1).saturating_sub(divisor.len())];
+        while remainder.len() >= divisor.len() {
+            // Cancel the leading coefficient of the remainder
+            let shift = remainder.len() - divisor.len();
+            let factor = *remainder.last().unwrap() * leading_inverse;
+            quotient[shift] = factor;
+            for (i, &c) in divisor.iter().enumerate() {
+                remainder[shift + i] -= factor * c;
+            }
+            remainder.pop();
+            trim_coefficients(&mut remainder);
+        }
+
+        Ok((
+            Self::from_values(self.field, quotient),
+            Self::from_values(self.field, remainder),
+        ))
+    }
+
+    /// The monic greatest common divisor, by Euclid's algorithm.
+    /// The gcd of zero and zero is zero.
+    ///
+    /// Fails if the coefficients aren't in a field.
+    pub fn gcd(&self, rhs: &Self) -> Result<Self, GFError> {
+        assert_eq!(self.field, rhs.field);
+        if !self.field.is_field() {
+            return Err(GFError::NotAField(self.field));
+        }
+
+        let (mut a, mut b) = (self.clone(), rhs.clone());
+        while !b.is_zero() {
+            let remainder = a.div_rem(&b)?.1;
+            (a, b) = (b, remainder);
+        }
+
+        if a.is_zero() {
+            Ok(a)
+        } else {
+            a.monic()
+        }
+    }
+
+    /// Raises to the given power modulo `modulus`, by repeated squaring.
+    ///
+    /// Fails under the same conditions as dividing by `modulus`.
+    pub fn pow_mod(&self, mut exponent: u64, modulus: &Self) -> Result<Self, GFError> {
+        let mut result = Self::one(self.field).div_rem(modulus)?.1;
+        let mut power = self.div_rem(modulus)?.1;
+        while exponent != 0 {
+            if exponent & 1 != 0 {
+                result = (&result * &power).div_rem(modulus)?.1;
+            }
+            power = (&power * &power).div_rem(modulus)?.1;
+            exponent >>= 1;
+        }
+        Ok(result)
+    }
+
+    /// The polynomial of the lowest degree that goes through all the given points,
+    /// found by Lagrange interpolation.
+    ///
+    /// Fails if the coefficients aren't in a field, or two points share an x.
+    pub fn interpolate(field: GF, points: &[(GFInt, GFInt)]) -> Result<Self, GFError> {
+        if !field.is_field() {
+            return Err(GFError::NotAField(field));
+        }
+
+        let mut result = Self::zero(field);
+        for (i, &(x_i, y_i)) in points.iter().enumerate() {
+            // The basis polynomial is 1 at x_i, and 0 at every other point
+            let mut basis = Self::one(field);
+            let mut denominator = field.create_value(1);
+            for (j, &(x_j, _)) in points.iter().enumerate() {
+                if i != j {
+                    basis = &basis * &Self::from_values(field, vec![-x_j, field.create_value(1)]);
+                    denominator *= x_i - x_j;
+                }
+            }
+
+            let weight = (y_i / denominator).map_err(|_| GFError::DivisionByZero)?;
+            result = &result + &basis.scale(weight);
+        }
+
+        Ok(result)
+    }
+
+    fn scale(&self, factor: GFInt) -> Self {
+        Self::from_values(
+            self.field,
+            self.coefficients.iter().map(|&c| c * factor).collect(),
+        )
+    }
+
+    fn trim(&mut self) {
+        trim_coefficients(&mut self.coefficients);
+    }
+}
+
+fn trim_coefficients(coefficients: &mut Vec<GFInt>) {
+    while coefficients.last().is_some_and(|c| c.value() == 0) {
+        coefficients.pop();
+    }
+}
+
+impl Add for &Polynomial {
+    type Output = Polynomial;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        assert_eq!(self.field, rhs.field);
+
+        let sum = self
+            .coefficients
+            .iter()
+            .zip_longest(&rhs.coefficients)
+            .map(|pair| match pair {
+                EitherOrBoth::Both(&a, &b) => a + b,
+                EitherOrBoth::Left(&a) => a,
+                EitherOrBoth::Right(&b) => b,
+            })
+            .collect();
+        Polynomial::from_values(self.field, sum)
+    }
+}
+
+impl Sub for &Polynomial {
+    type Output = Polynomial;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + &(-rhs)
+    }
+}
+
+impl Neg for &Polynomial {
+    type Output = Polynomial;
+
+    fn neg(self) -> Self::Output {
+        Polynomial::from_values(self.field, self.coefficients.iter().map(|&c| -c).collect())
+    }
+}
+
+impl Mul for &Polynomial {
+    type Output = Polynomial;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        assert_eq!(self.field, rhs.field);
+
+        if self.is_zero() || rhs.is_zero() {
+            return Polynomial::zero(self.field);
+        }
+
+        let mut product =
+            vec![self.field.create_value(0); self.coefficients.len() + rhs.coefficients.len() - 1];
+        for (i, &a) in self.coefficients.iter().enumerate() {
+            for (j, &b) in rhs.coefficients.iter().enumerate() {
+                product[i + j] += a * b;
+            }
+        }
+        Polynomial::from_values(self.field, product)
+    }
+}
+
+/// Writes the terms from the highest power of x down, such as `2x^2 + x + 1`.
+impl Display for Polynomial {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+
+        let terms = self
+            .coefficients
+            .iter()
+            .enumerate()
+            .rev()
+            .filter(|(_, c)| c.value() != 0)
+            .map(|(power, c)| {
+                let coefficient = match (c.value(), power) {
+                    (1, 1..) => String::new(),
+                    (value, _) => value.to_string(),
+                };
+                match power {
+                    0 => coefficient,
+                    1 => format!("{}x", coefficient),
+                    _ => format!("{}x^{}", coefficient, power),
+                }
+            });
+        write!(f, "{}", terms.format(" + "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroU32;
+
+    use super::Polynomial;
+    use crate::galois::{GFError, GF};
+
+    fn field(order: u32) -> GF {
+        GF::new(NonZeroU32::new(order))
+    }
+
+    #[test]
+    fn arithmetic() {
+        let f = field(7);
+        let a = Polynomial::new(f, &[1, 2, 3]);
+        let b = Polynomial::new(f, &[6, 5, 4, 0]);
+        assert_eq!(b.degree(), Some(2));
+        assert_eq!(&a + &b, Polynomial::new(f, &[0, 0, 0]));
+        assert!((&a + &b).is_zero());
+        assert_eq!(&a - &b, Polynomial::new(f, &[2, 4, 6]));
+        assert_eq!(&a * &b, Polynomial::new(f, &[6, 3, 4, 2, 5]));
+        assert_eq!(a.to_string(), "3x^2 + 2x + 1");
+        assert_eq!(Polynomial::new(f, &[0, 1, 0, 6]).to_string(), "6x^3 + x");
+        assert_eq!(a.eval(f.create_value(2)).value(), 3);
+    }
+
+    #[test]
+    fn division() {
+        let f = field(7);
+        let a = Polynomial::new(f, &[5, 0, 3, 1, 2]);
+        let b = Polynomial::new(f, &[1, 3]);
+        let (quotient, remainder) = a.div_rem(&b).unwrap();
+        assert_eq!(&(&quotient * &b) + &remainder, a);
+        assert!(remainder.degree().is_none_or(|degree| degree < 1));
+
+        assert_eq!(
+            a.div_rem(&Polynomial::zero(f)),
+            Err(GFError::DivisionByZero)
+        );
+
+        // Monic divisors work outside of fields too
+        let ring = field(8);
+        let (quotient, remainder) = Polynomial::new(ring, &[3, 2, 1])
+            .div_rem(&Polynomial::new(ring, &[1, 1]))
+            .unwrap();
+        assert_eq!(quotient, Polynomial::new(ring, &[1, 1]));
+        assert_eq!(remainder, Polynomial::new(ring, &[2]));
+    }
+
+    #[test]
+    fn gcd() {
+        let f = field(5);
+        // (x + 1)(x + 2) and (x + 1)(x + 3), times constants
+        let a = &Polynomial::new(f, &[2, 3, 1]) * &Polynomial::new(f, &[3]);
+        let b = Polynomial::new(f, &[3, 4, 1]);
+        assert_eq!(a.gcd(&b), Ok(Polynomial::new(f, &[1, 1])));
+        assert_eq!(
+            Polynomial::zero(f).gcd(&Polynomial::zero(f)),
+            Ok(Polynomial::zero(f))
+        );
+        assert!(Polynomial::new(field(6), &[1])
+            .gcd(&Polynomial::new(field(6), &[1]))
+            .is_err());
+
+        let x = Polynomial::x(f);
+        let modulus = Polynomial::new(f, &[2, 0, 1]);
+        // x^2 = 3, so x^5 = 9x = 4x
+        assert_eq!(x.pow_mod(5, &modulus), Ok(Polynomial::new(f, &[0, 4])));
+    }
+
+    #[test]
+    fn interpolation() {
+        let f = field(13);
+        let secret = Polynomial::new(f, &[7, 11, 2, 5]);
+        let points: Vec<_> = [1, 4, 9, 12]
+            .into_iter()
+            .map(|x| {
+                let x = f.create_value(x);
+                (x, secret.eval(x))
+            })
+            .collect();
+        assert_eq!(Polynomial::interpolate(f, &points), Ok(secret));
+        assert_eq!(Polynomial::interpolate(f, &[]), Ok(Polynomial::zero(f)));
+
+        let repeated = [points[0], points[0]];
+        assert_eq!(
+            Polynomial::interpolate(f, &repeated),
+            Err(GFError::DivisionByZero)
+        );
+    }
+}
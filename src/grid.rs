@@ -0,0 +1,218 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use nalgebra::{Point2, Vector2};
+use ndarray::{Array2, ArrayView1, ArrayView2, ArrayViewMut2};
+
+use crate::parse::{char_grid, parse_all, ParseError};
+
+// Unit steps in each direction. Rows grow downwards, so north is -y.
+pub const N: Vector2<isize> = Vector2::new(0, -1);
+pub const NE: Vector2<isize> = Vector2::new(1, -1);
+pub const E: Vector2<isize> = Vector2::new(1, 0);
+pub const SE: Vector2<isize> = Vector2::new(1, 1);
+pub const S: Vector2<isize> = Vector2::new(0, 1);
+pub const SW: Vector2<isize> = Vector2::new(-1, 1);
+pub const W: Vector2<isize> = Vector2::new(-1, 0);
+pub const NW: Vector2<isize> = Vector2::new(-1, -1);
+
+/// The four directions along rows and columns, clockwise from north.
+pub const ORTHOGONAL: [Vector2<isize>; 4] = [N, E, S, W];
+
+/// All eight directions, clockwise from north.
+pub const ALL_DIRECTIONS: [Vector2<isize>; 8] = [N, NE, E, SE, S, SW, W, NW];
+
+/// A rectangular grid of cells, addressed by points whose x coordinate
+/// is the column and whose y coordinate is the row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Array2<T>,
+}
+
+impl<T> Grid<T> {
+    /// Wraps an array indexed by (row, column).
+    pub fn new(cells: Array2<T>) -> Self {
+        Self { cells }
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Point2<usize>) -> T) -> Self {
+        Self::new(Array2::from_shape_fn((height, width), |(y, x)| {
+            f(Point2::new(x, y))
+        }))
+    }
+
+    /// Parses a map of characters, converting each with `cell`.
+    /// Characters for which `cell` returns `None` are an error,
+    /// as are lines of differing lengths.
+    pub fn parse(
+        input: &str,
+        cell: impl FnMut(char) -> Option<T> + Copy,
+    ) -> Result<Self, ParseError> {
+        parse_all(input, char_grid(cell)).map(Self::new)
+    }
+
+    pub fn width(&self) -> usize {
+        self.cells.ncols()
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.nrows()
+    }
+
+    pub fn contains(&self, point: Point2<usize>) -> bool {
+        point.x < self.width() && point.y < self.height()
+    }
+
+    pub fn get(&self, point: Point2<usize>) -> Option<&T> {
+        self.cells.get((point.y, point.x))
+    }
+
+    pub fn get_mut(&mut self, point: Point2<usize>) -> Option<&mut T> {
+        self.cells.get_mut((point.y, point.x))
+    }
+
+    /// Moves from a point by the given offset.
+    /// Returns `None` if the destination is outside the grid.
+    pub fn step(&self, point: Point2<usize>, offset: Vector2<isize>) -> Option<Point2<usize>> {
+        let destination = Point2::new(
+            point.x.checked_add_signed(offset.x)?,
+            point.y.checked_add_signed(offset.y)?,
+        );
+        self.contains(destination).then_some(destination)
+    }
+
+    /// The points above, to the right of, below and to the left of the given point,
+    /// where those are inside the grid.
+    pub fn neighbours4(&self, point: Point2<usize>) -> impl Iterator<Item = Point2<usize>> + '_ {
+        ORTHOGONAL
+            .into_iter()
+            .filter_map(move |direction| self.step(point, direction))
+    }
+
+    /// The points surrounding the given point, including diagonally,
+    /// where those are inside the grid.
+    pub fn neighbours8(&self, point: Point2<usize>) -> impl Iterator<Item = Point2<usize>> + '_ {
+        ALL_DIRECTIONS
+            .into_iter()
+            .filter_map(move |direction| self.step(point, direction))
+    }
+
+    /// The points from the given point to the edge of the grid,
+    /// repeatedly moving in the given direction. The starting point
+    /// itself is not included.
+    pub fn ray(
+        &self,
+        point: Point2<usize>,
+        direction: Vector2<isize>,
+    ) -> impl Iterator<Item = Point2<usize>> + '_ {
+        assert_ne!(direction, Vector2::zeros());
+        std::iter::successors(self.step(point, direction), move |&point| {
+            self.step(point, direction)
+        })
+    }
+
+    /// All points of the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point2<usize>> {
+        let width = self.width();
+        (0..self.height()).flat_map(move |y| (0..width).map(move |x| Point2::new(x, y)))
+    }
+
+    /// All cells of the grid along with their points, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point2<usize>, &T)> {
+        self.cells
+            .indexed_iter()
+            .map(|((y, x), cell)| (Point2::new(x, y), cell))
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Point2<usize>, &mut T)> {
+        self.cells
+            .indexed_iter_mut()
+            .map(|((y, x), cell)| (Point2::new(x, y), cell))
+    }
+
+    pub fn row(&self, y: usize) -> ArrayView1<'_, T> {
+        self.cells.row(y)
+    }
+
+    pub fn column(&self, x: usize) -> ArrayView1<'_, T> {
+        self.cells.column(x)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.cells.map(f))
+    }
+
+    /// The underlying array, indexed by (row, column).
+    pub fn view(&self) -> ArrayView2<'_, T> {
+        self.cells.view()
+    }
+
+    pub fn view_mut(&mut self) -> ArrayViewMut2<'_, T> {
+        self.cells.view_mut()
+    }
+
+    pub fn into_array(self) -> Array2<T> {
+        self.cells
+    }
+}
+
+impl<T> Index<Point2<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point2<usize>) -> &Self::Output {
+        &self.cells[(point.y, point.x)]
+    }
+}
+
+impl<T> IndexMut<Point2<usize>> for Grid<T> {
+    fn index_mut(&mut self, point: Point2<usize>) -> &mut Self::Output {
+        &mut self.cells[(point.y, point.x)]
+    }
+}
+
+impl<T> From<Array2<T>> for Grid<T> {
+    fn from(cells: Array2<T>) -> Self {
+        Self::new(cells)
+    }
+}
+
+/// Draws each cell with its own `Display`, one line per row.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.cells.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::Point2;
+
+    use super::{Grid, E, NW};
+
+    #[test]
+    fn neighbours_stay_inside() {
+        let grid = Grid::from_fn(3, 2, |point| point.x + point.y);
+        assert_eq!(grid.neighbours4(Point2::new(0, 0)).count(), 2);
+        assert_eq!(grid.neighbours8(Point2::new(1, 0)).count(), 5);
+        assert_eq!(grid.neighbours8(Point2::new(1, 1)).count(), 5);
+    }
+
+    #[test]
+    fn rays() {
+        let grid = Grid::parse("123\n456\n789", |c| c.to_digit(10)).unwrap();
+        let values = |ray: &mut dyn Iterator<Item = Point2<usize>>| {
+            ray.map(|point| grid[point]).collect::<Vec<_>>()
+        };
+        assert_eq!(values(&mut grid.ray(Point2::new(0, 1), E)), [5, 6]);
+        assert_eq!(values(&mut grid.ray(Point2::new(2, 2), NW)), [5, 1]);
+        assert_eq!(grid.to_string(), "123\n456\n789\n");
+    }
+}
@@ -1,2 +1,10 @@
+pub mod answer;
+pub mod days;
 pub mod galois;
+pub mod grid;
+pub mod ocr;
+pub mod parse;
+pub mod radix;
+pub mod search;
+pub mod solution;
 pub mod util;
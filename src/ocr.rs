@@ -0,0 +1,135 @@
+use std::fmt::Display;
+
+use ndarray::{s, ArrayView2};
+
+/// Height of a letter in the font, in pixels.
+pub const GLYPH_HEIGHT: usize = 6;
+
+/// Width of a letter in the font, in pixels. Letters are separated
+/// by a single blank column.
+pub const GLYPH_WIDTH: usize = 4;
+
+/// The letters of the font that the puzzles draw with.
+const GLYPHS: [(char, [&str; GLYPH_HEIGHT]); 17] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// An error in recognizing the letters of an image.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OcrError {
+    /// The image can't be split into whole letters.
+    BadSize { height: usize, width: usize },
+    /// The letter at the given index isn't in the font.
+    /// The glyph is drawn with `#` and `.`, one line per row.
+    UnknownGlyph { index: usize, glyph: String },
+}
+
+impl Display for OcrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OcrError::BadSize { height, width } => write!(
+                f,
+                "A {}x{} image can't be split into {}x{} letters",
+                width, height, GLYPH_WIDTH, GLYPH_HEIGHT
+            ),
+            OcrError::UnknownGlyph { index, glyph } => {
+                write!(f, "Letter {} is not in the font:\n{}", index + 1, glyph)
+            }
+        }
+    }
+}
+
+impl std::error::Error for OcrError {}
+
+/// Reads the letters drawn in an image, indexed by (row, column).
+///
+/// The image must be exactly one letter high, and each letter must
+/// be followed by a blank column, except possibly the last one.
+pub fn recognize(image: ArrayView2<bool>) -> Result<String, OcrError> {
+    let (height, width) = image.dim();
+    let stride = GLYPH_WIDTH + 1;
+    if height != GLYPH_HEIGHT
+        || width == 0
+        || (width % stride != 0 && width % stride != GLYPH_WIDTH)
+    {
+        return Err(OcrError::BadSize { height, width });
+    }
+
+    (0..width.div_ceil(stride))
+        .map(|index| {
+            let start = index * stride;
+            let glyph = image.slice(s![.., start..(start + stride).min(width)]);
+
+            let rows: Vec<String> = glyph
+                .rows()
+                .into_iter()
+                .map(|row| row.iter().map(|&lit| if lit { '#' } else { '.' }).collect())
+                .collect();
+
+            GLYPHS
+                .iter()
+                .find(|(_, pattern)| {
+                    rows.iter().zip(pattern).all(|(row, pattern)| {
+                        // The separating column, if any, must be blank
+                        row.starts_with(pattern) && row[GLYPH_WIDTH..].chars().all(|c| c == '.')
+                    })
+                })
+                .map(|&(letter, _)| letter)
+                .ok_or_else(|| OcrError::UnknownGlyph {
+                    index,
+                    glyph: rows.join("\n"),
+                })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use ndarray::Array2;
+
+    use super::{recognize, OcrError};
+
+    fn image(rows: &[&str]) -> Array2<bool> {
+        let width = rows[0].len();
+        let pixels = rows.iter().flat_map(|row| row.chars().map(|c| c == '#'));
+        Array2::from_shape_vec((rows.len(), width), pixels.collect()).unwrap()
+    }
+
+    #[test]
+    fn letters() {
+        let image = image(&[
+            "#..#..###",
+            "#..#...#.",
+            "####...#.",
+            "#..#...#.",
+            "#..#...#.",
+            "#..#..###",
+        ]);
+        assert_eq!(recognize(image.view()), Ok("HI".to_owned()));
+    }
+
+    #[test]
+    fn unknown_glyph() {
+        let image = image(&["####.", "#..#.", "#..#.", "#..#.", "#..#.", "####."]);
+        assert!(matches!(
+            recognize(image.view()),
+            Err(OcrError::UnknownGlyph { index: 0, .. })
+        ));
+    }
+}
@@ -0,0 +1,325 @@
+use std::{fmt::Display, str::FromStr};
+
+use nalgebra::{Point2, Point3, Scalar};
+use ndarray::Array2;
+use nom::{
+    branch::alt,
+    character::complete::{char, digit1, line_ending, none_of},
+    combinator::{all_consuming, cut, eof, map, map_opt, map_res, opt, peek, recognize},
+    error::ErrorKind,
+    multi::{many1, separated_list1},
+    sequence::{pair, separated_pair, tuple},
+    IResult, Parser,
+};
+
+/// An error in the puzzle input, pointing at where it was found.
+///
+/// Lines and columns are 1-based. Columns are counted in characters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    line: usize,
+    column: usize,
+    message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, message: impl Into<String>) -> Self {
+        Self {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    /// Creates an error pointing at the start of `position`,
+    /// which must be a slice of `input`.
+    pub fn at(input: &str, position: &str, message: impl Into<String>) -> Self {
+        let offset = (position.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|&offset| offset + position.len() <= input.len())
+            .expect("Position is not within the input");
+        let consumed = &input[..offset];
+
+        let line = consumed.matches('\n').count() + 1;
+        let line_start = consumed.rfind('\n').map_or(0, |index| index + 1);
+        let column = consumed[line_start..].chars().count() + 1;
+
+        Self::new(line, column, message)
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    fn from_nom(input: &str, error: nom::Err<nom::error::Error<&str>>) -> Self {
+        match error {
+            nom::Err::Incomplete(_) => {
+                Self::at(input, &input[input.len()..], "Unexpected end of input")
+            }
+            nom::Err::Error(error) | nom::Err::Failure(error) => {
+                let code = error.code;
+                let expected = match code {
+                    ErrorKind::Digit => "expected a number",
+                    ErrorKind::Tag | ErrorKind::Char | ErrorKind::OneOf | ErrorKind::NoneOf => {
+                        "expected a different character"
+                    }
+                    ErrorKind::MapRes | ErrorKind::MapOpt => "value is out of range or invalid",
+                    ErrorKind::Verify => "line length differs from the first line",
+                    ErrorKind::Eof => "expected end of line",
+                    ErrorKind::CrLf => "expected a line break",
+                    _ => code.description(),
+                };
+
+                let snippet = error.input.lines().next().unwrap_or_default();
+                let message = if snippet.is_empty() {
+                    format!("Unexpected end of line ({})", expected)
+                } else {
+                    format!("Unexpected '{}' ({})", snippet, expected)
+                };
+                Self::at(input, error.input, message)
+            }
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Runs the parser over the entire input. Trailing newlines are ignored,
+/// but anything else left over is an error.
+pub fn parse_all<'a, O>(
+    input: &'a str,
+    parser: impl Parser<&'a str, O, nom::error::Error<&'a str>>,
+) -> Result<O, ParseError> {
+    let trimmed = input.trim_end_matches(['\r', '\n']);
+    match all_consuming(parser)(trimmed) {
+        Ok((_, output)) => Ok(output),
+        Err(error) => Err(ParseError::from_nom(trimmed, error)),
+    }
+}
+
+/// Runs the parser over each line of the input separately.
+/// Each line must be consumed entirely.
+pub fn parse_lines<'a, O>(
+    input: &'a str,
+    mut parser: impl Parser<&'a str, O, nom::error::Error<&'a str>>,
+) -> Result<Vec<O>, ParseError> {
+    input
+        .lines()
+        .map(
+            |line| match all_consuming(|line| parser.parse(line))(line) {
+                Ok((_, output)) => Ok(output),
+                Err(error) => Err(ParseError::from_nom(input, error)),
+            },
+        )
+        .collect()
+}
+
+/// Converts each line of the input with a fallible function.
+///
+/// Errors from the function are reported at the start of the offending line.
+pub fn map_lines<O>(
+    input: &str,
+    mut f: impl FnMut(&str) -> anyhow::Result<O>,
+) -> Result<Vec<O>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            f(line).map_err(|error| ParseError::new(index + 1, 1, format!("{:#}", error)))
+        })
+        .collect()
+}
+
+/// Converts each block of lines of the input with a fallible function.
+/// Blocks are separated by empty lines.
+///
+/// Errors from the function are reported at the start of the offending block.
+pub fn map_blocks<O>(
+    input: &str,
+    mut f: impl FnMut(&[&str]) -> anyhow::Result<O>,
+) -> Result<Vec<O>, ParseError> {
+    let lines: Vec<&str> = input.lines().collect();
+
+    let mut first_line = 0;
+    lines
+        .split(|line| line.is_empty())
+        .map(|block| {
+            let line = first_line + 1;
+            first_line += block.len() + 1;
+            f(block).map_err(|error| ParseError::new(line, 1, format!("{:#}", error)))
+        })
+        .collect()
+}
+
+/// Parses an unsigned decimal integer.
+pub fn unsigned<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(digit1, str::parse)(input)
+}
+
+/// Parses a decimal integer with an optional leading minus sign.
+pub fn signed<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(recognize(pair(opt(char('-')), digit1)), str::parse)(input)
+}
+
+/// Parses a comma-separated pair of coordinates, such as `3,-4`.
+pub fn point2<'a, T, F>(coordinate: F) -> impl FnMut(&'a str) -> IResult<&'a str, Point2<T>>
+where
+    T: Scalar,
+    F: Parser<&'a str, T, nom::error::Error<&'a str>> + Copy,
+{
+    map(
+        separated_pair(coordinate, char(','), coordinate),
+        |(x, y)| Point2::new(x, y),
+    )
+}
+
+/// Parses a comma-separated triplet of coordinates, such as `1,2,3`.
+pub fn point3<'a, T, F>(coordinate: F) -> impl FnMut(&'a str) -> IResult<&'a str, Point3<T>>
+where
+    T: Scalar,
+    F: Parser<&'a str, T, nom::error::Error<&'a str>> + Copy,
+{
+    map(
+        tuple((coordinate, char(','), coordinate, char(','), coordinate)),
+        |(x, _, y, _, z)| Point3::new(x, y, z),
+    )
+}
+
+/// Parses a non-empty list of points, such as `498,4 -> 498,6 -> 496,6`.
+pub fn point_list<'a, T, F, S, O>(
+    coordinate: F,
+    separator: S,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<Point2<T>>>
+where
+    T: Scalar,
+    F: Parser<&'a str, T, nom::error::Error<&'a str>> + Copy,
+    S: Parser<&'a str, O, nom::error::Error<&'a str>>,
+{
+    separated_list1(separator, point2(coordinate))
+}
+
+/// Parses blocks of input separated by empty lines.
+pub fn blocks<'a, O, F>(block: F) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>>
+where
+    F: Parser<&'a str, O, nom::error::Error<&'a str>>,
+{
+    separated_list1(pair(line_ending, line_ending), block)
+}
+
+/// Parses a rectangular grid of characters, converting each with `cell`.
+/// Characters for which `cell` returns `None` are an error.
+pub fn char_grid<'a, T, F>(cell: F) -> impl FnMut(&'a str) -> IResult<&'a str, Array2<T>>
+where
+    F: FnMut(char) -> Option<T> + Copy,
+{
+    move |input| {
+        let (remaining, rows) = grid_rows(cell)(input)?;
+
+        let width = rows[0].1.len();
+        if let Some((row, _)) = rows.iter().find(|(_, cells)| cells.len() != width) {
+            return Err(nom::Err::Failure(nom::error::Error::new(
+                row,
+                ErrorKind::Verify,
+            )));
+        }
+
+        let height = rows.len();
+        let cells = rows.into_iter().flat_map(|(_, cells)| cells).collect();
+        let grid = Array2::from_shape_vec((height, width), cells).unwrap();
+
+        Ok((remaining, grid))
+    }
+}
+
+/// Parses a grid of characters whose lines may have different lengths,
+/// converting each with `cell`. Short lines are padded at the end
+/// with the default value.
+pub fn ragged_char_grid<'a, T, F>(cell: F) -> impl FnMut(&'a str) -> IResult<&'a str, Array2<T>>
+where
+    T: Default + Clone,
+    F: FnMut(char) -> Option<T> + Copy,
+{
+    move |input| {
+        let (remaining, rows) = grid_rows(cell)(input)?;
+
+        let width = rows.iter().map(|(_, cells)| cells.len()).max().unwrap();
+        let mut grid = Array2::default((rows.len(), width));
+        for (y, (_, cells)) in rows.into_iter().enumerate() {
+            for (x, cell) in cells.into_iter().enumerate() {
+                grid[(y, x)] = cell;
+            }
+        }
+
+        Ok((remaining, grid))
+    }
+}
+
+/// Parses lines of cells, along with the input at the start of each line.
+#[allow(clippy::type_complexity)]
+fn grid_rows<'a, T, F>(cell: F) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<(&'a str, Vec<T>)>>
+where
+    F: FnMut(char) -> Option<T> + Copy,
+{
+    move |input| {
+        separated_list1(line_ending, |line: &'a str| {
+            let (remaining, cells) = many1(map_opt(none_of("\r\n"), cell))(line)?;
+
+            // Once a line has started, every character on it must be a valid cell
+            let (remaining, _) = cut(peek(alt((line_ending, eof))))(remaining)?;
+
+            Ok((remaining, (line, cells)))
+        })(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::Point2;
+    use nom::bytes::complete::tag;
+
+    use super::{char_grid, parse_all, parse_lines, point_list, signed, unsigned, ParseError};
+
+    #[test]
+    fn error_location() {
+        let error = parse_lines::<u32>("12\n34\n5x6", unsigned).unwrap_err();
+        assert_eq!((error.line(), error.column()), (3, 2));
+    }
+
+    #[test]
+    fn signed_points() {
+        let points = parse_all("1,-2 -> -3,4", point_list(signed::<i32>, tag(" -> "))).unwrap();
+        assert_eq!(points, vec![Point2::new(1, -2), Point2::new(-3, 4)]);
+    }
+
+    #[test]
+    fn invalid_cell_location() {
+        let grid = char_grid(|c| c.to_digit(10));
+        let error = parse_all("123\n4x6\n", grid).unwrap_err();
+        assert_eq!((error.line(), error.column()), (2, 2));
+    }
+
+    #[test]
+    fn ragged_grid_is_an_error() {
+        let grid = char_grid(|c| c.to_digit(10));
+        let error: ParseError = parse_all("123\n45\n678", grid).unwrap_err();
+        assert_eq!((error.line(), error.column()), (2, 1));
+    }
+}
@@ -0,0 +1,933 @@
+//! Arbitrary-precision integers written in a given base, with all arithmetic
+//! done directly on the digits.
+//!
+//! [`RadixInt`] uses the ordinary digits, from zero up to one less than the base,
+//! along with a sign. [`BalancedInt`] uses digits centered around zero instead,
+//! such as -2 to 2 in balanced base 5, so that negative numbers need no sign.
+//!
+//! The characters the digits are written with come from an [`Alphabet`].
+
+use std::{
+    cmp::Ordering,
+    fmt::{Debug, Display},
+    hash::Hash,
+    iter,
+    marker::PhantomData,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign},
+    str::FromStr,
+};
+
+use itertools::{EitherOrBoth, Itertools};
+
+use crate::answer::Answer;
+
+/// The characters digits are written with, from the lowest digit up.
+/// Only as many characters as the base are used.
+///
+/// Alphabets are marker types. They derive the usual traits,
+/// so that the numbers written with them can too.
+pub trait Alphabet: Debug + Clone + Default + PartialEq + Eq + Hash {
+    const DIGITS: &'static str;
+}
+
+/// `0` to `9`, followed by `a` to `z`, for bases up to 36.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Alphanumeric;
+
+impl Alphabet for Alphanumeric {
+    const DIGITS: &'static str = "0123456789abcdefghijklmnopqrstuvwxyz";
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RadixError {
+    /// There are no digits to parse.
+    Empty,
+    /// The character isn't one of the digits of the base.
+    InvalidDigit(char),
+    /// The number doesn't fit in the primitive type it's converted to.
+    Overflow,
+}
+
+impl Display for RadixError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RadixError::Empty => write!(f, "Expected at least one digit"),
+            RadixError::InvalidDigit(digit) => write!(f, "Invalid digit {}", digit),
+            RadixError::Overflow => write!(f, "Number too large for the target type"),
+        }
+    }
+}
+
+impl std::error::Error for RadixError {}
+
+/// An integer in balanced base `BASE`, which must be odd. The digits go from
+/// `-(BASE - 1) / 2` to `(BASE - 1) / 2`, and the sign of a number
+/// is the sign of its leading digit.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BalancedInt<const BASE: u8, A> {
+    /// Least significant first, without leading zeros.
+    /// Zero has no digits at all.
+    digits: Vec<i8>,
+    alphabet: PhantomData<A>,
+}
+
+/// An integer in base `BASE`, written with the digits from 0 to `BASE - 1`,
+/// and a leading `-` if it's negative.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RadixInt<const BASE: u8, A = Alphanumeric> {
+    /// Never set for zero.
+    negative: bool,
+    /// The magnitude, least significant digit first, without leading zeros.
+    digits: Vec<i8>,
+    alphabet: PhantomData<A>,
+}
+
+impl<const BASE: u8, A: Alphabet> BalancedInt<BASE, A> {
+    /// Also checks the base and the alphabet, so every constructor uses it.
+    const LOWEST_DIGIT: i8 = {
+        assert!(
+            BASE % 2 == 1 && BASE <= 127,
+            "Balanced bases must be odd, and at most 127"
+        );
+        assert!(
+            char_count(A::DIGITS) >= BASE as usize,
+            "The alphabet has fewer digits than the base"
+        );
+        -((BASE / 2) as i8)
+    };
+
+    pub fn zero() -> Self {
+        Self::from_digits(vec![])
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.digits.is_empty()
+    }
+
+    /// The digits, least significant first. Zero has none.
+    pub fn digits(&self) -> &[i8] {
+        &self.digits
+    }
+
+    /// Returns -1, 0 or 1, according to the sign of the number.
+    pub fn signum(&self) -> i8 {
+        self.digits.last().map_or(0, |digit| digit.signum())
+    }
+
+    pub fn abs(&self) -> Self {
+        if self.signum() < 0 {
+            -self
+        } else {
+            self.clone()
+        }
+    }
+
+    /// Divides, rounding towards zero, and returns both the quotient and the remainder.
+    /// The remainder takes the sign of the dividend, like for primitive integers.
+    ///
+    /// # Panics
+    ///
+    /// If `rhs` is zero.
+    pub fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        assert!(!rhs.is_zero(), "attempt to divide by zero");
+
+        let (quotient, remainder) = div_rem_digits(
+            &self.abs().digits,
+            &rhs.abs().digits,
+            BASE.into(),
+            Self::LOWEST_DIGIT.into(),
+        );
+        let (mut quotient, mut remainder) =
+            (Self::from_digits(quotient), Self::from_digits(remainder));
+
+        if self.signum() * rhs.signum() < 0 {
+            quotient = -quotient;
+        }
+        if self.signum() < 0 {
+            remainder = -remainder;
+        }
+
+        (quotient, remainder)
+    }
+
+    fn from_digits(digits: Vec<i8>) -> Self {
+        let _ = Self::LOWEST_DIGIT;
+        Self {
+            digits,
+            alphabet: PhantomData,
+        }
+    }
+
+    fn from_i128(mut value: i128) -> Self {
+        let base = i128::from(BASE);
+        let highest_digit = -i128::from(Self::LOWEST_DIGIT);
+
+        let mut digits = vec![];
+        while value != 0 {
+            // Pick the digit that leaves a multiple of the base.
+            // Dividing first keeps this from overflowing.
+            let mut quotient = value.div_euclid(base);
+            let mut digit = value.rem_euclid(base);
+            if digit > highest_digit {
+                digit -= base;
+                quotient += 1;
+            }
+
+            digits.push(digit.try_into().unwrap());
+            value = quotient;
+        }
+
+        Self::from_digits(digits)
+    }
+
+    fn to_i128(&self) -> Result<i128, RadixError> {
+        let base = i128::from(BASE);
+        let mut result: i128 = 0;
+        for &digit in self.digits.iter().rev() {
+            // A digit with the opposite sign pulls the product back towards zero,
+            // but only after the product alone may have gone past the limit,
+            // as for i128::MIN in base 5. Borrowing one from the prefix avoids that.
+            let (prefix, digit) = match (result.signum(), digit.signum()) {
+                (-1, 1) => (result + 1, i128::from(digit) - base),
+                (1, -1) => (result - 1, i128::from(digit) + base),
+                _ => (result, digit.into()),
+            };
+            result = prefix
+                .checked_mul(base)
+                .and_then(|result| result.checked_add(digit))
+                .ok_or(RadixError::Overflow)?;
+        }
+        Ok(result)
+    }
+
+    fn digit_to_char(digit: i8) -> char {
+        let index = usize::try_from(digit - Self::LOWEST_DIGIT).unwrap();
+        A::DIGITS
+            .chars()
+            .nth(index)
+            .expect("The alphabet has fewer digits than the base")
+    }
+
+    fn char_to_digit(c: char) -> Option<i8> {
+        let index = A::DIGITS
+            .chars()
+            .take(BASE.into())
+            .position(|digit| digit == c)?;
+        Some(i8::try_from(index).unwrap() + Self::LOWEST_DIGIT)
+    }
+}
+
+impl<const BASE: u8, A: Alphabet> RadixInt<BASE, A> {
+    /// Also checks the base and the alphabet, so every constructor uses it.
+    const LOWEST_DIGIT: i8 = {
+        assert!(BASE >= 2 && BASE <= 127, "Bases must be between 2 and 127");
+        assert!(
+            char_count(A::DIGITS) >= BASE as usize,
+            "The alphabet has fewer digits than the base"
+        );
+        0
+    };
+
+    pub fn zero() -> Self {
+        Self::from_parts(false, vec![])
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.digits.is_empty()
+    }
+
+    /// The digits of the magnitude, least significant first. Zero has none.
+    pub fn digits(&self) -> &[i8] {
+        &self.digits
+    }
+
+    /// Returns -1, 0 or 1, according to the sign of the number.
+    pub fn signum(&self) -> i8 {
+        if self.negative {
+            -1
+        } else if self.is_zero() {
+            0
+        } else {
+            1
+        }
+    }
+
+    pub fn abs(&self) -> Self {
+        Self::from_parts(false, self.digits.clone())
+    }
+
+    /// Divides, rounding towards zero, and returns both the quotient and the remainder.
+    /// The remainder takes the sign of the dividend, like for primitive integers.
+    ///
+    /// # Panics
+    ///
+    /// If `rhs` is zero.
+    pub fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        assert!(!rhs.is_zero(), "attempt to divide by zero");
+
+        let (quotient, remainder) = div_rem_digits(
+            &self.digits,
+            &rhs.digits,
+            BASE.into(),
+            Self::LOWEST_DIGIT.into(),
+        );
+        (
+            Self::from_parts(self.negative != rhs.negative, quotient),
+            Self::from_parts(self.negative, remainder),
+        )
+    }
+
+    fn from_parts(negative: bool, digits: Vec<i8>) -> Self {
+        let _ = Self::LOWEST_DIGIT;
+        Self {
+            negative: negative && !digits.is_empty(),
+            digits,
+            alphabet: PhantomData,
+        }
+    }
+
+    fn from_i128(value: i128) -> Self {
+        let base = u128::from(BASE);
+
+        let mut magnitude = value.unsigned_abs();
+        let mut digits = vec![];
+        while magnitude != 0 {
+            digits.push((magnitude % base).try_into().unwrap());
+            magnitude /= base;
+        }
+
+        Self::from_parts(value < 0, digits)
+    }
+
+    fn to_i128(&self) -> Result<i128, RadixError> {
+        // Accumulating with the sign already applied covers i128::MIN too
+        let sign = if self.negative { -1 } else { 1 };
+        let mut result: i128 = 0;
+        for &digit in self.digits.iter().rev() {
+            result = result
+                .checked_mul(BASE.into())
+                .and_then(|result| result.checked_add(sign * i128::from(digit)))
+                .ok_or(RadixError::Overflow)?;
+        }
+        Ok(result)
+    }
+
+    /// Adds a number with the given sign and digits.
+    fn add_signed(&self, negative: bool, digits: &[i8]) -> Self {
+        let base = BASE.into();
+        let low = Self::LOWEST_DIGIT.into();
+
+        if self.negative == negative {
+            return Self::from_parts(negative, add_digits(&self.digits, digits, 1, base, low));
+        }
+
+        // Subtract the smaller magnitude from the larger one,
+        // which keeps its sign
+        match compare_digits(&self.digits, digits) {
+            Ordering::Less => {
+                Self::from_parts(negative, add_digits(digits, &self.digits, -1, base, low))
+            }
+            _ => Self::from_parts(
+                self.negative,
+                add_digits(&self.digits, digits, -1, base, low),
+            ),
+        }
+    }
+
+    fn digit_to_char(digit: i8) -> char {
+        A::DIGITS
+            .chars()
+            .nth(digit.try_into().unwrap())
+            .expect("The alphabet has fewer digits than the base")
+    }
+
+    fn char_to_digit(c: char) -> Option<i8> {
+        let index = A::DIGITS
+            .chars()
+            .take(BASE.into())
+            .position(|digit| digit == c)?;
+        Some(index.try_into().unwrap())
+    }
+}
+
+impl<const BASE: u8, A: Alphabet> AddAssign<&Self> for BalancedInt<BASE, A> {
+    fn add_assign(&mut self, rhs: &Self) {
+        self.digits = add_digits(
+            &self.digits,
+            &rhs.digits,
+            1,
+            BASE.into(),
+            Self::LOWEST_DIGIT.into(),
+        );
+    }
+}
+
+impl<const BASE: u8, A: Alphabet> SubAssign<&Self> for BalancedInt<BASE, A> {
+    fn sub_assign(&mut self, rhs: &Self) {
+        self.digits = add_digits(
+            &self.digits,
+            &rhs.digits,
+            -1,
+            BASE.into(),
+            Self::LOWEST_DIGIT.into(),
+        );
+    }
+}
+
+impl<const BASE: u8, A: Alphabet> MulAssign<&Self> for BalancedInt<BASE, A> {
+    fn mul_assign(&mut self, rhs: &Self) {
+        self.digits = mul_digits(
+            &self.digits,
+            &rhs.digits,
+            BASE.into(),
+            Self::LOWEST_DIGIT.into(),
+        );
+    }
+}
+
+impl<const BASE: u8, A: Alphabet> DivAssign<&Self> for BalancedInt<BASE, A> {
+    fn div_assign(&mut self, rhs: &Self) {
+        *self = self.div_rem(rhs).0;
+    }
+}
+
+impl<const BASE: u8, A: Alphabet> RemAssign<&Self> for BalancedInt<BASE, A> {
+    fn rem_assign(&mut self, rhs: &Self) {
+        *self = self.div_rem(rhs).1;
+    }
+}
+
+impl<const BASE: u8, A: Alphabet> AddAssign<&Self> for RadixInt<BASE, A> {
+    fn add_assign(&mut self, rhs: &Self) {
+        *self = self.add_signed(rhs.negative, &rhs.digits);
+    }
+}
+
+impl<const BASE: u8, A: Alphabet> SubAssign<&Self> for RadixInt<BASE, A> {
+    fn sub_assign(&mut self, rhs: &Self) {
+        *self = self.add_signed(!rhs.negative, &rhs.digits);
+    }
+}
+
+impl<const BASE: u8, A: Alphabet> MulAssign<&Self> for RadixInt<BASE, A> {
+    fn mul_assign(&mut self, rhs: &Self) {
+        let digits = mul_digits(
+            &self.digits,
+            &rhs.digits,
+            BASE.into(),
+            Self::LOWEST_DIGIT.into(),
+        );
+        *self = Self::from_parts(self.negative != rhs.negative, digits);
+    }
+}
+
+impl<const BASE: u8, A: Alphabet> DivAssign<&Self> for RadixInt<BASE, A> {
+    fn div_assign(&mut self, rhs: &Self) {
+        *self = self.div_rem(rhs).0;
+    }
+}
+
+impl<const BASE: u8, A: Alphabet> RemAssign<&Self> for RadixInt<BASE, A> {
+    fn rem_assign(&mut self, rhs: &Self) {
+        *self = self.div_rem(rhs).1;
+    }
+}
+
+/// Implements a binary operator for every combination of owned and borrowed
+/// operands, on top of the assigning form of the operator that borrows its operand.
+macro_rules! forward_binop {
+    ($type:ident, $op:ident, $method:ident, $op_assign:ident, $method_assign:ident) => {
+        impl<const BASE: u8, A: Alphabet> $op_assign for $type<BASE, A> {
+            fn $method_assign(&mut self, rhs: Self) {
+                self.$method_assign(&rhs);
+            }
+        }
+
+        impl<const BASE: u8, A: Alphabet> $op for $type<BASE, A> {
+            type Output = Self;
+
+            fn $method(mut self, rhs: Self) -> Self::Output {
+                self.$method_assign(&rhs);
+                self
+            }
+        }
+
+        impl<const BASE: u8, A: Alphabet> $op<&$type<BASE, A>> for $type<BASE, A> {
+            type Output = Self;
+
+            fn $method(mut self, rhs: &$type<BASE, A>) -> Self::Output {
+                self.$method_assign(rhs);
+                self
+            }
+        }
+
+        impl<const BASE: u8, A: Alphabet> $op for &$type<BASE, A> {
+            type Output = $type<BASE, A>;
+
+            fn $method(self, rhs: Self) -> Self::Output {
+                self.clone().$method(rhs)
+            }
+        }
+    };
+}
+
+forward_binop!(BalancedInt, Add, add, AddAssign, add_assign);
+forward_binop!(BalancedInt, Sub, sub, SubAssign, sub_assign);
+forward_binop!(BalancedInt, Mul, mul, MulAssign, mul_assign);
+forward_binop!(BalancedInt, Div, div, DivAssign, div_assign);
+forward_binop!(BalancedInt, Rem, rem, RemAssign, rem_assign);
+
+forward_binop!(RadixInt, Add, add, AddAssign, add_assign);
+forward_binop!(RadixInt, Sub, sub, SubAssign, sub_assign);
+forward_binop!(RadixInt, Mul, mul, MulAssign, mul_assign);
+forward_binop!(RadixInt, Div, div, DivAssign, div_assign);
+forward_binop!(RadixInt, Rem, rem, RemAssign, rem_assign);
+
+impl<const BASE: u8, A: Alphabet> Neg for BalancedInt<BASE, A> {
+    type Output = Self;
+
+    fn neg(mut self) -> Self::Output {
+        for digit in &mut self.digits {
+            *digit = -*digit;
+        }
+        self
+    }
+}
+
+impl<const BASE: u8, A: Alphabet> Neg for &BalancedInt<BASE, A> {
+    type Output = BalancedInt<BASE, A>;
+
+    fn neg(self) -> Self::Output {
+        -self.clone()
+    }
+}
+
+impl<const BASE: u8, A: Alphabet> Neg for RadixInt<BASE, A> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::from_parts(!self.negative, self.digits)
+    }
+}
+
+impl<const BASE: u8, A: Alphabet> Neg for &RadixInt<BASE, A> {
+    type Output = RadixInt<BASE, A>;
+
+    fn neg(self) -> Self::Output {
+        -self.clone()
+    }
+}
+
+impl<const BASE: u8, A: Alphabet> Ord for BalancedInt<BASE, A> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        compare_digits(&self.digits, &other.digits)
+    }
+}
+
+impl<const BASE: u8, A: Alphabet> PartialOrd for BalancedInt<BASE, A> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const BASE: u8, A: Alphabet> Ord for RadixInt<BASE, A> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, false) => compare_digits(&self.digits, &other.digits),
+            (true, true) => compare_digits(&other.digits, &self.digits),
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+        }
+    }
+}
+
+impl<const BASE: u8, A: Alphabet> PartialOrd for RadixInt<BASE, A> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const BASE: u8, A: Alphabet> Default for BalancedInt<BASE, A> {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<const BASE: u8, A: Alphabet> Default for RadixInt<BASE, A> {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<const BASE: u8, A: Alphabet> Display for BalancedInt<BASE, A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_zero() {
+            return write!(f, "{}", Self::digit_to_char(0));
+        }
+
+        for &digit in self.digits.iter().rev() {
+            write!(f, "{}", Self::digit_to_char(digit))?;
+        }
+        Ok(())
+    }
+}
+
+impl<const BASE: u8, A: Alphabet> Display for RadixInt<BASE, A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_zero() {
+            return write!(f, "{}", Self::digit_to_char(0));
+        }
+
+        if self.negative {
+            write!(f, "-")?;
+        }
+        for &digit in self.digits.iter().rev() {
+            write!(f, "{}", Self::digit_to_char(digit))?;
+        }
+        Ok(())
+    }
+}
+
+impl<const BASE: u8, A: Alphabet> FromStr for BalancedInt<BASE, A> {
+    type Err = RadixError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut digits = s
+            .trim()
+            .chars()
+            .rev()
+            .map(|c| Self::char_to_digit(c).ok_or(RadixError::InvalidDigit(c)))
+            .collect::<Result<Vec<_>, _>>()?;
+        if digits.is_empty() {
+            return Err(RadixError::Empty);
+        }
+
+        trim_leading_zeros(&mut digits);
+        Ok(Self::from_digits(digits))
+    }
+}
+
+impl<const BASE: u8, A: Alphabet> FromStr for RadixInt<BASE, A> {
+    type Err = RadixError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (negative, s) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s),
+        };
+
+        let mut digits = s
+            .chars()
+            .rev()
+            .map(|c| Self::char_to_digit(c).ok_or(RadixError::InvalidDigit(c)))
+            .collect::<Result<Vec<_>, _>>()?;
+        if digits.is_empty() {
+            return Err(RadixError::Empty);
+        }
+
+        trim_leading_zeros(&mut digits);
+        Ok(Self::from_parts(negative, digits))
+    }
+}
+
+/// Implements conversions from and to primitive integers, going through `i128`.
+macro_rules! impl_conversions {
+    ($type:ident: $($int:ty),*) => {
+        $(
+            impl<const BASE: u8, A: Alphabet> From<$int> for $type<BASE, A> {
+                fn from(value: $int) -> Self {
+                    Self::from_i128(i128::try_from(value).unwrap())
+                }
+            }
+
+            impl<const BASE: u8, A: Alphabet> TryFrom<&$type<BASE, A>> for $int {
+                type Error = RadixError;
+
+                fn try_from(value: &$type<BASE, A>) -> Result<Self, Self::Error> {
+                    value
+                        .to_i128()?
+                        .try_into()
+                        .map_err(|_| RadixError::Overflow)
+                }
+            }
+
+            impl<const BASE: u8, A: Alphabet> TryFrom<$type<BASE, A>> for $int {
+                type Error = RadixError;
+
+                fn try_from(value: $type<BASE, A>) -> Result<Self, Self::Error> {
+                    (&value).try_into()
+                }
+            }
+        )*
+    };
+}
+
+impl_conversions!(BalancedInt: i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+impl_conversions!(RadixInt: i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+impl<const BASE: u8, A: Alphabet> From<BalancedInt<BASE, A>> for Answer {
+    fn from(value: BalancedInt<BASE, A>) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+impl<const BASE: u8, A: Alphabet> From<RadixInt<BASE, A>> for Answer {
+    fn from(value: RadixInt<BASE, A>) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+// The arithmetic below works on digits stored least significant first,
+// each between `low` and `low + base - 1`. That's zero and up for ordinary
+// bases, and centered around zero for balanced ones.
+
+/// The number of characters in a string, which `str::chars` can't count
+/// at compile time. Continuation bytes are the ones that start with `10`.
+const fn char_count(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    let mut count = 0;
+    let mut index = 0;
+    while index < bytes.len() {
+        if bytes[index] & 0xc0 != 0x80 {
+            count += 1;
+        }
+        index += 1;
+    }
+    count
+}
+
+fn trim_leading_zeros(digits: &mut Vec<i8>) {
+    while digits.last() == Some(&0) {
+        digits.pop();
+    }
+}
+
+/// Brings digits that may have gone out of range back into it, by carrying
+/// the excess over to the next digit. The number must be representable:
+/// for ordinary bases, it mustn't be negative.
+fn propagate_carries(digits: impl IntoIterator<Item = i64>, base: i64, low: i64) -> Vec<i8> {
+    let mut digits = digits.into_iter();
+    let mut result = vec![];
+    let mut carry = 0;
+    loop {
+        let value = match digits.next() {
+            Some(digit) => digit + carry,
+            None if carry == 0 => break,
+            None => {
+                assert!(
+                    carry > 0 || low < 0,
+                    "Negative numbers need negative digits"
+                );
+                carry
+            }
+        };
+
+        let digit = (value - low).rem_euclid(base) + low;
+        carry = (value - digit) / base;
+        result.push(digit.try_into().unwrap());
+    }
+
+    trim_leading_zeros(&mut result);
+    result
+}
+
+/// Adds `sign * b` to `a`, where `sign` is 1 or -1.
+fn add_digits(a: &[i8], b: &[i8], sign: i64, base: i64, low: i64) -> Vec<i8> {
+    let sums = a.iter().zip_longest(b).map(|pair| match pair {
+        EitherOrBoth::Both(&a, &b) => i64::from(a) + sign * i64::from(b),
+        EitherOrBoth::Left(&a) => a.into(),
+        EitherOrBoth::Right(&b) => sign * i64::from(b),
+    });
+    propagate_carries(sums, base, low)
+}
+
+/// Long multiplication: multiplies every pair of digits, and only then carries.
+fn mul_digits(a: &[i8], b: &[i8], base: i64, low: i64) -> Vec<i8> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+
+    let mut products = vec![0i64; a.len() + b.len() - 1];
+    for (i, &a) in a.iter().enumerate() {
+        for (j, &b) in b.iter().enumerate() {
+            products[i + j] += i64::from(a) * i64::from(b);
+        }
+    }
+    propagate_carries(products, base, low)
+}
+
+/// Compares two numbers digit by digit, from the most significant one.
+/// This works for balanced digits too, since no combination of lower digits
+/// can make up for a difference of one in a higher digit.
+fn compare_digits(a: &[i8], b: &[i8]) -> Ordering {
+    let digit = |digits: &[i8], index: usize| digits.get(index).copied().unwrap_or(0);
+    (0..a.len().max(b.len()))
+        .rev()
+        .map(|index| digit(a, index).cmp(&digit(b, index)))
+        .find(|ordering| ordering.is_ne())
+        .unwrap_or(Ordering::Equal)
+}
+
+/// Long division of two numbers that aren't negative, bringing down one digit
+/// at a time. Returns the quotient and the remainder.
+fn div_rem_digits(a: &[i8], b: &[i8], base: i64, low: i64) -> (Vec<i8>, Vec<i8>) {
+    let mut quotient = vec![];
+    let mut remainder: Vec<i8> = vec![];
+    for &digit in a.iter().rev() {
+        let shifted = iter::once(digit.into()).chain(remainder.iter().map(|&digit| digit.into()));
+        remainder = propagate_carries(shifted, base, low);
+
+        // The remainder was less than the divisor, so a few steps
+        // either way bring it back between zero and the divisor.
+        // A negative digit brought down can make it negative.
+        let mut quotient_digit = 0;
+        while compare_digits(&remainder, &[]).is_lt() {
+            remainder = add_digits(&remainder, b, 1, base, low);
+            quotient_digit -= 1;
+        }
+        while compare_digits(&remainder, b).is_ge() {
+            remainder = add_digits(&remainder, b, -1, base, low);
+            quotient_digit += 1;
+        }
+
+        quotient.push(quotient_digit);
+    }
+
+    // The quotient digits may be out of range for balanced bases
+    quotient.reverse();
+    (propagate_carries(quotient, base, low), remainder)
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::iproduct;
+
+    use super::{Alphabet, BalancedInt, RadixError, RadixInt};
+    use crate::days::day25::SnafuInt;
+
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+    struct Signs;
+
+    impl Alphabet for Signs {
+        const DIGITS: &'static str = "-0+";
+    }
+
+    type Ternary = BalancedInt<3, Signs>;
+
+    /// Balanced base 7, with letters standing for the negative digits.
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+    struct Letters;
+
+    impl Alphabet for Letters {
+        const DIGITS: &'static str = "cba0123";
+    }
+
+    type Septenary = BalancedInt<7, Letters>;
+
+    #[test]
+    fn formatting() {
+        assert_eq!(RadixInt::<16>::from(-255).to_string(), "-ff");
+        assert_eq!(RadixInt::<2>::from(10).to_string(), "1010");
+        assert_eq!(RadixInt::<10>::from(0).to_string(), "0");
+        assert_eq!(Ternary::from(8).to_string(), "+0-");
+        assert_eq!(Ternary::from(-8).to_string(), "-0+");
+        assert_eq!(Ternary::from(0).to_string(), "0");
+
+        assert_eq!("-00ff".parse(), Ok(RadixInt::<16>::from(-255)));
+        assert_eq!("+0-".parse(), Ok(Ternary::from(8)));
+        assert_eq!(
+            "12".parse::<RadixInt<2>>(),
+            Err(RadixError::InvalidDigit('2'))
+        );
+        assert_eq!("".parse::<Ternary>(), Err(RadixError::Empty));
+        assert_eq!("-0".parse::<RadixInt<10>>().unwrap().signum(), 0);
+    }
+
+    #[test]
+    fn conversions() {
+        assert_eq!(
+            u8::try_from(RadixInt::<10>::from(300)),
+            Err(RadixError::Overflow)
+        );
+        assert_eq!(i8::try_from(Ternary::from(-128)), Ok(-128));
+
+        for value in [i128::MIN, i128::MAX, -1, 0, 1] {
+            assert_eq!(i128::try_from(RadixInt::<7>::from(value)), Ok(value));
+            assert_eq!(i128::try_from(Septenary::from(value)), Ok(value));
+            assert_eq!(i128::try_from(SnafuInt::from(value)), Ok(value));
+        }
+        assert_eq!(
+            i128::try_from(SnafuInt::from(i128::MAX) + SnafuInt::from(1)),
+            Err(RadixError::Overflow)
+        );
+        assert_eq!(
+            i128::try_from(SnafuInt::from(i128::MIN) - SnafuInt::from(1)),
+            Err(RadixError::Overflow)
+        );
+    }
+
+    #[test]
+    fn arithmetic_matches_primitives() {
+        for (a, b) in iproduct!(-60i64..=60, -60i64..=60) {
+            check_arithmetic::<RadixInt<10>>(a, b);
+            check_arithmetic::<RadixInt<2>>(a, b);
+            check_arithmetic::<Ternary>(a, b);
+            check_arithmetic::<Septenary>(a, b);
+            check_arithmetic::<SnafuInt>(a, b);
+        }
+        assert_eq!(-SnafuInt::from(12345), SnafuInt::from(-12345));
+    }
+
+    #[test]
+    fn large_numbers() {
+        let a: i128 = 123_456_789_012_345_678_901;
+        let b: i128 = -987_654_321_098;
+        check_large_arithmetic::<RadixInt<10>>(a, b);
+        check_large_arithmetic::<Septenary>(a, b);
+        check_large_arithmetic::<SnafuInt>(a, b);
+
+        assert_eq!(
+            Septenary::from(i128::MIN) + Septenary::from(i128::MAX),
+            Septenary::from(-1)
+        );
+        assert_eq!(
+            SnafuInt::from(i128::MIN) + SnafuInt::from(i128::MAX),
+            SnafuInt::from(-1)
+        );
+    }
+
+    fn check_large_arithmetic<T>(a: i128, b: i128)
+    where
+        T: From<i128> + PartialEq + std::fmt::Debug,
+        for<'a> &'a T:
+            std::ops::Mul<Output = T> + std::ops::Div<Output = T> + std::ops::Rem<Output = T>,
+    {
+        let (x, y) = (T::from(a), T::from(b));
+        assert_eq!(&x * &y, T::from(a * b));
+        assert_eq!(&x / &y, T::from(a / b));
+        assert_eq!(&x % &y, T::from(a % b));
+    }
+
+    fn check_arithmetic<T>(a: i64, b: i64)
+    where
+        T: From<i64> + Ord + std::fmt::Debug,
+        for<'a> &'a T: std::ops::Add<Output = T>
+            + std::ops::Sub<Output = T>
+            + std::ops::Mul<Output = T>
+            + std::ops::Div<Output = T>
+            + std::ops::Rem<Output = T>
+            + std::ops::Neg<Output = T>,
+    {
+        let (x, y) = (T::from(a), T::from(b));
+        assert_eq!(&x + &y, T::from(a + b), "{} + {}", a, b);
+        assert_eq!(&x - &y, T::from(a - b), "{} - {}", a, b);
+        assert_eq!(&x * &y, T::from(a * b), "{} * {}", a, b);
+        assert_eq!(-&x, T::from(-a), "-{}", a);
+        assert_eq!(x.cmp(&y), a.cmp(&b), "{} <=> {}", a, b);
+        if b != 0 {
+            assert_eq!(&x / &y, T::from(a / b), "{} / {}", a, b);
+            assert_eq!(&x % &y, T::from(a % b), "{} % {}", a, b);
+        }
+    }
+}
@@ -0,0 +1,371 @@
+//! Generic searches over graphs given by a successor function.
+//!
+//! Nodes are discovered lazily, so the graph may be infinite, as long as
+//! the search is bound to reach a goal.
+
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::{hash_map::Entry, BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+use num_traits::Zero;
+
+/// A path through a graph, along with its total cost.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N, C> {
+    nodes: Vec<N>,
+    cost: C,
+}
+
+impl<N, C: Copy> Path<N, C> {
+    /// The nodes along the path, from the start to the end, inclusive.
+    pub fn nodes(&self) -> &[N] {
+        &self.nodes
+    }
+
+    pub fn into_nodes(self) -> Vec<N> {
+        self.nodes
+    }
+
+    pub fn cost(&self) -> C {
+        self.cost
+    }
+
+    pub fn start(&self) -> &N {
+        self.nodes.first().unwrap()
+    }
+
+    pub fn end(&self) -> &N {
+        self.nodes.last().unwrap()
+    }
+}
+
+/// A node waiting in a priority queue. Only the cost takes part in comparisons.
+struct Weighted<C, N> {
+    cost: C,
+    node: N,
+}
+
+impl<C: PartialEq, N> PartialEq for Weighted<C, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cost == other.cost
+    }
+}
+
+impl<C: Eq, N> Eq for Weighted<C, N> {}
+
+impl<C: Ord, N> PartialOrd for Weighted<C, N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C: Ord, N> Ord for Weighted<C, N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.cost.cmp(&other.cost)
+    }
+}
+
+/// Follows the parent links back from the end node to a node without a parent.
+fn reconstruct<N: Eq + Hash + Clone>(parents: &HashMap<N, N>, end: N) -> Vec<N> {
+    let mut nodes = vec![end];
+    while let Some(parent) = parents.get(nodes.last().unwrap()) {
+        nodes.push(parent.clone());
+    }
+    nodes.reverse();
+    nodes
+}
+
+/// Breadth-first search for the path with the fewest steps to a node
+/// for which `success` returns `true`.
+pub fn bfs<N, FN, IN, FS>(start: N, successors: FN, success: FS) -> Option<Path<N, usize>>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FS: FnMut(&N) -> bool,
+{
+    bfs_multi([start], successors, success)
+}
+
+/// Like [`bfs`], but searches from all of the `starts` at once. The path found
+/// begins at whichever of them is closest to a goal.
+pub fn bfs_multi<N, IS, FN, IN, FS>(
+    starts: IS,
+    mut successors: FN,
+    mut success: FS,
+) -> Option<Path<N, usize>>
+where
+    N: Eq + Hash + Clone,
+    IS: IntoIterator<Item = N>,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FS: FnMut(&N) -> bool,
+{
+    let mut parents = HashMap::new();
+    let mut to_visit = VecDeque::new();
+    let mut discovered = HashSet::new();
+
+    for start in starts {
+        if discovered.insert(start.clone()) {
+            to_visit.push_back((start, 0));
+        }
+    }
+
+    while let Some((current, steps)) = to_visit.pop_front() {
+        if success(&current) {
+            return Some(Path {
+                nodes: reconstruct(&parents, current),
+                cost: steps,
+            });
+        }
+
+        for successor in successors(&current) {
+            if discovered.insert(successor.clone()) {
+                parents.insert(successor.clone(), current.clone());
+                to_visit.push_back((successor, steps + 1));
+            }
+        }
+    }
+
+    None
+}
+
+/// Dijkstra's algorithm. Finds the cheapest path to a node for which `success`
+/// returns `true`. `successors` returns each neighbour along with the cost
+/// of moving to it, which must not be negative.
+pub fn dijkstra<N, C, FN, IN, FS>(start: N, successors: FN, success: FS) -> Option<Path<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FS: FnMut(&N) -> bool,
+{
+    astar(start, successors, |_| C::zero(), success)
+}
+
+/// A* search. Like [`dijkstra`], but explores nodes in order of their cost so far
+/// plus the `heuristic` estimate of the remaining cost. The heuristic must
+/// never overestimate, or the path found may not be the cheapest.
+///
+/// <https://en.wikipedia.org/wiki/A*_search_algorithm>
+pub fn astar<N, C, FN, IN, FH, FS>(
+    start: N,
+    mut successors: FN,
+    mut heuristic: FH,
+    mut success: FS,
+) -> Option<Path<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FH: FnMut(&N) -> C,
+    FS: FnMut(&N) -> bool,
+{
+    let mut open_set = BinaryHeap::new();
+    open_set.push(Reverse(Weighted {
+        cost: heuristic(&start),
+        node: start.clone(),
+    }));
+
+    let mut g_score = HashMap::from([(start, C::zero())]);
+    let mut parents = HashMap::new();
+
+    while let Some(Reverse(Weighted {
+        cost,
+        node: current,
+    })) = open_set.pop()
+    {
+        let current_g_score = g_score[&current];
+
+        // A cheaper route to this node was found after it was queued
+        if cost > current_g_score + heuristic(&current) {
+            continue;
+        }
+
+        if success(&current) {
+            return Some(Path {
+                nodes: reconstruct(&parents, current),
+                cost: current_g_score,
+            });
+        }
+
+        for (successor, step_cost) in successors(&current) {
+            let tentative_g_score = current_g_score + step_cost;
+            match g_score.entry(successor.clone()) {
+                Entry::Occupied(entry) if *entry.get() <= tentative_g_score => continue,
+                Entry::Occupied(mut entry) => {
+                    entry.insert(tentative_g_score);
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(tentative_g_score);
+                }
+            }
+
+            parents.insert(successor.clone(), current.clone());
+            open_set.push(Reverse(Weighted {
+                cost: tentative_g_score + heuristic(&successor),
+                node: successor,
+            }));
+        }
+    }
+
+    None
+}
+
+/// Best-first branch and bound. Finds the node with the highest `value`
+/// among all nodes reachable from the start.
+///
+/// `bound` must never be lower than the value of the node or of any node
+/// reachable from it. Nodes are explored in order of decreasing bound,
+/// and whole subtrees are skipped once their bound shows they can't beat
+/// the best value found so far.
+///
+/// The graph is searched as a tree: nodes reachable along several paths
+/// are explored once for each.
+pub fn branch_and_bound<N, V, FN, IN, FV, FB>(
+    start: N,
+    mut successors: FN,
+    mut value: FV,
+    mut bound: FB,
+) -> Path<N, V>
+where
+    V: Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FV: FnMut(&N) -> V,
+    FB: FnMut(&N) -> V,
+{
+    // Every discovered node, along with the index of its parent
+    let mut nodes: Vec<(N, Option<usize>)> = vec![];
+
+    let mut best_value = value(&start);
+    let mut best = 0;
+
+    let mut open_set = BinaryHeap::new();
+    open_set.push(Weighted {
+        cost: bound(&start),
+        node: 0,
+    });
+    nodes.push((start, None));
+
+    while let Some(Weighted {
+        cost: upper_bound,
+        node: current,
+    }) = open_set.pop()
+    {
+        // Nothing left in the queue can do any better
+        if upper_bound <= best_value {
+            break;
+        }
+
+        for successor in successors(&nodes[current].0) {
+            let successor_value = value(&successor);
+            let successor_bound = bound(&successor);
+
+            let index = nodes.len();
+            if successor_value > best_value {
+                best_value = successor_value;
+                best = index;
+            }
+
+            if successor_bound > best_value || best == index {
+                nodes.push((successor, Some(current)));
+                if successor_bound > best_value {
+                    open_set.push(Weighted {
+                        cost: successor_bound,
+                        node: index,
+                    });
+                }
+            }
+        }
+    }
+
+    let mut path = vec![];
+    let mut current = Some(best);
+    while let Some(index) = current {
+        path.push(index);
+        current = nodes[index].1;
+    }
+
+    // Move the nodes on the path out of the arena, in order
+    let mut nodes: Vec<Option<N>> = nodes.into_iter().map(|(node, _)| Some(node)).collect();
+    Path {
+        nodes: path
+            .into_iter()
+            .rev()
+            .map(|index| nodes[index].take().unwrap())
+            .collect(),
+        cost: best_value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{astar, bfs, bfs_multi, branch_and_bound, dijkstra};
+
+    #[test]
+    fn bfs_finds_fewest_steps() {
+        // Each number leads to its double and its successor
+        let path = bfs(1u32, |&n| [n * 2, n + 1], |&n| n == 10).unwrap();
+        assert_eq!(path.nodes(), [1, 2, 4, 5, 10]);
+        assert_eq!(path.cost(), 4);
+    }
+
+    #[test]
+    fn bfs_multi_starts_from_closest() {
+        let path = bfs_multi([1u32, 3, 8], |&n| [n * 2, n + 1], |&n| n == 10).unwrap();
+        assert_eq!(path.nodes(), [8, 9, 10]);
+        assert_eq!(path.cost(), 2);
+    }
+
+    #[test]
+    fn dijkstra_and_astar_agree() {
+        // Doubling is expensive, incrementing is cheap
+        let successors = |&n: &u32| [(n * 2, 5u32), (n + 1, 1)];
+        let path = dijkstra(1, successors, |&n| n == 10).unwrap();
+        assert_eq!(path.cost(), 9);
+
+        let path = astar(
+            1,
+            successors,
+            |&n| 10u32.saturating_sub(n).min(1),
+            |&n| n == 10,
+        );
+        assert_eq!(path.unwrap().cost(), 9);
+    }
+
+    #[test]
+    fn branch_and_bound_finds_maximum() {
+        // Pick digits left to right to maximize (first * second) - third,
+        // with each digit between 0 and 3
+        let path = branch_and_bound(
+            vec![],
+            |digits: &Vec<i32>| {
+                if digits.len() < 3 {
+                    (0..4)
+                        .map(|digit| [&digits[..], &[digit]].concat())
+                        .collect()
+                } else {
+                    vec![]
+                }
+            },
+            |digits| match digits[..] {
+                [a, b, c] => a * b - c,
+                _ => i32::MIN,
+            },
+            |digits| match digits[..] {
+                [a, b, c] => a * b - c,
+                [a, b] => a * b,
+                [a] => a * 3,
+                _ => 9,
+            },
+        );
+        assert_eq!(path.cost(), 9);
+        assert_eq!(path.end(), &vec![3, 3, 0]);
+        assert_eq!(path.nodes().len(), 4);
+    }
+}
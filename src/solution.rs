@@ -0,0 +1,66 @@
+use anyhow::{bail, Result};
+
+use crate::{answer::Answer, util::input_string};
+
+/// A single day's puzzle.
+///
+/// The input is parsed once, and both parts are then solved
+/// from the parsed representation.
+pub trait Solution {
+    type Part1: Into<Answer>;
+    type Part2: Into<Answer>;
+
+    /// The number of parts in the puzzle. Only the last day has just one.
+    const PARTS: u8 = 2;
+
+    fn parse(input: &str) -> Result<Self>
+    where
+        Self: Sized;
+
+    fn part1(&self) -> Result<Self::Part1>;
+
+    fn part2(&self) -> Result<Self::Part2>;
+
+    /// Renders the graph the puzzle is solved on in Graphviz DOT format,
+    /// for the days that build one. Fails if the input has no sensible graph.
+    fn to_dot(&self) -> Option<Result<String>> {
+        None
+    }
+}
+
+/// Object-safe view of a [`Solution`], with the answers converted to [`Answer`].
+pub trait AnySolution {
+    fn parts(&self) -> u8;
+
+    fn solve(&self, part: u8) -> Result<Answer>;
+
+    fn to_dot(&self) -> Option<Result<String>>;
+}
+
+impl<S: Solution> AnySolution for S {
+    fn parts(&self) -> u8 {
+        S::PARTS
+    }
+
+    fn solve(&self, part: u8) -> Result<Answer> {
+        match part {
+            1 => Ok(self.part1()?.into()),
+            2 if S::PARTS >= 2 => Ok(self.part2()?.into()),
+            _ => bail!("No such part {}", part),
+        }
+    }
+
+    fn to_dot(&self) -> Option<Result<String>> {
+        Solution::to_dot(self)
+    }
+}
+
+/// Solves every part of the puzzle using the input given on the command line,
+/// printing each answer on its own line. This is the whole of each day's binary.
+pub fn run<S: Solution>() -> Result<()> {
+    let solution = S::parse(&input_string()?)?;
+    for part in 1..=S::PARTS {
+        println!("{}", solution.solve(part)?);
+    }
+    Ok(())
+}
@@ -2,7 +2,7 @@
     env,
     ffi::OsStr,
     fs::File,
-    io::{BufRead, BufReader},
+    io::{BufRead, BufReader, Read},
     ops::{Add, Sub},
     path::PathBuf,
 };
@@ -35,6 +35,19 @@ pub fn input_file() -> Result<File> {
     Ok(File::open(input_filename)?)
 }
 
+/// Returns the default location of the input for the given day.
+pub fn day_input_path(day: u8) -> PathBuf {
+    [INPUTS_DIRECTORY, &format!("day{}.txt", day)]
+        .iter()
+        .collect()
+}
+
+pub fn input_string() -> Result<String> {
+    let mut input = String::new();
+    input_file()?.read_to_string(&mut input)?;
+    Ok(input)
+}
+
 pub fn input_lines() -> Result<Vec<String>> {
     let reader = BufReader::new(input_file()?);
 
@@ -0,0 +1,267 @@
+//! Checks every day against the example from the puzzle text.
+
+use std::fmt::Debug;
+
+use aoc2022::{
+    answer::Answer,
+    days::{
+        day01::Day01,
+        day02::Day02,
+        day03::Day03,
+        day04::Day04,
+        day05::Day05,
+        day06::Day06,
+        day07::Day07,
+        day08::Day08,
+        day09::Day09,
+        day10::Day10,
+        day11::Day11,
+        day12::Day12,
+        day13::Day13,
+        day14::Day14,
+        day15::Day15,
+        day16::{plan_valves, Day16},
+        day17::Day17,
+        day18::Day18,
+        day19::Day19,
+        day20::Day20,
+        day21::Day21,
+        day22::Day22,
+        day23::Day23,
+        day24::Day24,
+        day25::Day25,
+    },
+    solution::Solution,
+};
+
+fn check<S>(solution: S, part1: S::Part1, part2: S::Part2)
+where
+    S: Solution,
+    S::Part1: PartialEq + Debug,
+    S::Part2: PartialEq + Debug,
+{
+    assert_eq!(solution.part1().unwrap(), part1);
+    assert_eq!(solution.part2().unwrap(), part2);
+}
+
+macro_rules! example {
+    ($day:ty, $file:literal) => {
+        <$day>::parse(include_str!(concat!("examples/", $file))).unwrap()
+    };
+}
+
+#[test]
+fn day01() {
+    check(example!(Day01, "day01.txt"), 24000, 45000);
+}
+
+#[test]
+fn day02() {
+    check(example!(Day02, "day02.txt"), 15, 12);
+}
+
+#[test]
+fn day03() {
+    check(example!(Day03, "day03.txt"), 157, 70);
+}
+
+#[test]
+fn day04() {
+    check(example!(Day04, "day04.txt"), 2, 4);
+}
+
+#[test]
+fn day05() {
+    check(example!(Day05, "day05.txt"), "CMZ".into(), "MCD".into());
+}
+
+#[test]
+fn day06() {
+    check(example!(Day06, "day06.txt"), 7, 19);
+}
+
+#[test]
+fn day07() {
+    check(example!(Day07, "day07.txt"), 95437, 24933642);
+
+    let dot = example!(Day07, "day07.txt").to_dot().unwrap().unwrap();
+    assert!(dot.contains(r#"label = "e (dir, size=584)", shape = folder"#));
+    assert!(dot.contains(r#"label = "i (file, size=584)", shape = note"#));
+}
+
+#[test]
+fn day08() {
+    check(example!(Day08, "day08.txt"), 21, 8);
+}
+
+#[test]
+fn day09() {
+    check(example!(Day09, "day09.txt"), 13, 1);
+}
+
+#[test]
+fn day10() {
+    let solution = example!(Day10, "day10.txt");
+    assert_eq!(solution.part1().unwrap(), 13140);
+
+    // The example draws a test pattern rather than letters
+    assert!(solution.part2().is_err());
+
+    let screen = Answer::from(solution.screen());
+    assert_eq!(
+        screen.to_string(),
+        "\
+        ##..##..##..##..##..##..##..##..##..##..\n\
+        ###...###...###...###...###...###...###.\n\
+        ####....####....####....####....####....\n\
+        #####.....#####.....#####.....#####.....\n\
+        ######......######......######......####\n\
+        #######.......#######.......#######....."
+    );
+}
+
+#[test]
+fn day11() {
+    check(example!(Day11, "day11.txt"), 10605, 2713310158);
+}
+
+#[test]
+fn day12() {
+    check(example!(Day12, "day12.txt"), 31, 29);
+
+    let solution = example!(Day12, "day12.txt");
+    let map = solution.map();
+    let path = map.find_shortest_path(map.start()).unwrap();
+    assert_eq!(path.nodes().len(), 32);
+    assert_eq!(
+        map.render_path(path.nodes()).to_string(),
+        ">>vv<<<<\n\
+         abvvv<<^\n\
+         acvv>E^^\n\
+         acv>>>^^\n\
+         ab>>>>>^\n"
+    );
+}
+
+#[test]
+fn day13() {
+    check(example!(Day13, "day13.txt"), 13, 140);
+}
+
+#[test]
+fn day14() {
+    check(example!(Day14, "day14.txt"), 24, 93);
+}
+
+#[test]
+fn day15() {
+    let solution = example!(Day15, "day15.txt").with_bounds(10, 0, 20);
+    check(solution, 26, 56000011);
+}
+
+#[test]
+fn day16() {
+    check(example!(Day16, "day16.txt"), 1651, 1707);
+
+    let solution = example!(Day16, "day16.txt");
+    let schedule = plan_valves(solution.valves(), 26, 2, "AA").unwrap();
+    let openings: Vec<_> = schedule
+        .openings()
+        .iter()
+        .map(|opening| (opening.minute(), opening.valve()))
+        .collect();
+    assert_eq!(
+        openings,
+        [
+            (2, "DD"),
+            (3, "JJ"),
+            (7, "HH"),
+            (7, "BB"),
+            (9, "CC"),
+            (11, "EE")
+        ]
+    );
+
+    assert_eq!(solution.with_agents(3).part2().unwrap(), 1794);
+
+    let dot = example!(Day16, "day16.txt").to_dot().unwrap().unwrap();
+    assert!(dot.contains(r#"label = "AA\nflow rate=0", shape = doublecircle"#));
+    assert_eq!(dot.matches(" -- ").count(), 7 * 6 / 2);
+}
+
+#[test]
+fn day17() {
+    check(example!(Day17, "day17.txt"), 3068, 1514285714288);
+}
+
+#[test]
+fn day18() {
+    check(example!(Day18, "day18.txt"), 64, 58);
+}
+
+#[test]
+fn day19() {
+    check(example!(Day19, "day19.txt"), 33, 56 * 62);
+}
+
+#[test]
+fn day20() {
+    check(example!(Day20, "day20.txt"), 3, 1623178306);
+}
+
+#[test]
+fn day21() {
+    check(example!(Day21, "day21.txt"), 152, 301);
+    check(
+        example!(Day21, "day21.txt").with_names("sjmn", "dbpl"),
+        150,
+        30,
+    );
+
+    let dot = example!(Day21, "day21.txt").to_dot().unwrap().unwrap();
+    assert!(dot.contains(r#"label = "root: pppw + sjmn\n= 152""#));
+    assert!(dot.contains(r#"label = "humn: 5""#));
+}
+
+#[test]
+fn day22() {
+    check(example!(Day22, "day22.txt"), 6032, 5031);
+}
+
+#[test]
+fn day23() {
+    check(example!(Day23, "day23.txt"), 110, 20);
+}
+
+#[test]
+fn day24() {
+    check(example!(Day24, "day24.txt"), 18, 54);
+
+    let solution = example!(Day24, "day24.txt");
+    let map = solution.map();
+    let route = map
+        .find_shortest_path(&[map.entrance(), map.exit()], 0)
+        .unwrap();
+    let route: Vec<_> = route.iter().map(ToString::to_string).collect();
+    assert_eq!(
+        route.join(", "),
+        "down, down, wait, up, right, right, down, left, up, right, wait, \
+         down, down, right, right, right, down, down"
+    );
+
+    // Repeating a waypoint doesn't make the route any longer
+    let repeated = map
+        .find_shortest_path(&[map.entrance(), map.entrance(), map.exit(), map.exit()], 0)
+        .unwrap();
+    assert_eq!(repeated.len(), route.len());
+    assert_eq!(
+        map.find_shortest_path(&[map.exit(), map.exit()], 18),
+        Some(vec![])
+    );
+}
+
+#[test]
+fn day25() {
+    let solution = example!(Day25, "day25.txt");
+    assert_eq!(solution.part1().unwrap().to_string(), "2=-1=0");
+}
@@ -0,0 +1,61 @@
+//! Checks every day against the puzzle inputs in the inputs directory,
+//! using the answers listed in inputs/answers.txt.
+//!
+//! Days without an input file are skipped.
+
+use std::{fs, path::Path};
+
+use aoc2022::{days, util::day_input_path};
+
+#[derive(Debug)]
+struct Expected {
+    day: u8,
+    part: u8,
+    answer: String,
+}
+
+fn read_manifest() -> Vec<Expected> {
+    let manifest = fs::read_to_string(Path::new("inputs").join("answers.txt")).unwrap();
+
+    manifest
+        .lines()
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let mut fields = line.splitn(3, ' ');
+            let mut field = || fields.next().expect("Malformed manifest line");
+            Expected {
+                day: field().parse().unwrap(),
+                part: field().parse().unwrap(),
+                answer: field().replace("\\n", "\n"),
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn puzzle_inputs() {
+    let mut failures = vec![];
+
+    for expected in read_manifest() {
+        let Ok(input) = fs::read_to_string(day_input_path(expected.day)) else {
+            continue;
+        };
+
+        let answer = days::parse(expected.day, &input)
+            .and_then(|solution| solution.solve(expected.part))
+            .map(|answer| answer.to_string());
+        match answer {
+            Ok(answer) if answer == expected.answer => {}
+            Ok(answer) => failures.push(format!(
+                "Day {} part {}: expected {:?}, got {:?}",
+                expected.day, expected.part, expected.answer, answer
+            )),
+            Err(error) => failures.push(format!(
+                "Day {} part {}: {:#}",
+                expected.day, expected.part, error
+            )),
+        }
+    }
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}